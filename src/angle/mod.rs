@@ -0,0 +1,137 @@
+use std::f32::consts::{PI, TAU};
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// Angle in radians. There is deliberately no conversion from a bare `f32`,
+/// so a value in degrees cannot be passed where radians are expected.
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default)]
+pub struct Radians(pub f32);
+
+/// Angle in degrees.
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default)]
+pub struct Degrees(pub f32);
+
+impl Radians {
+    pub fn to_degrees(self) -> Degrees {
+        Degrees(self.0.to_degrees())
+    }
+
+    /// Equivalent angle in `(-π, π]`.
+    pub fn normalize(self) -> Radians {
+        let r = self.normalize_positive().0;
+        Radians(if r > PI { r - TAU } else { r })
+    }
+
+    /// Equivalent angle in `[0, 2π)`.
+    pub fn normalize_positive(self) -> Radians {
+        let r = self.0.rem_euclid(TAU);
+        Radians(if r >= TAU { 0. } else { r })
+    }
+
+    pub fn sin_cos(self) -> (f32, f32) {
+        self.0.sin_cos()
+    }
+}
+
+impl Degrees {
+    pub fn to_radians(self) -> Radians {
+        Radians(self.0.to_radians())
+    }
+
+    /// Equivalent angle in `(-180, 180]`.
+    pub fn normalize(self) -> Degrees {
+        let d = self.normalize_positive().0;
+        Degrees(if d > 180. { d - 360. } else { d })
+    }
+
+    /// Equivalent angle in `[0, 360)`.
+    pub fn normalize_positive(self) -> Degrees {
+        let d = self.0.rem_euclid(360.);
+        Degrees(if d >= 360. { 0. } else { d })
+    }
+
+    pub fn sin_cos(self) -> (f32, f32) {
+        self.to_radians().sin_cos()
+    }
+}
+
+impl From<Degrees> for Radians {
+    fn from(value: Degrees) -> Self {
+        value.to_radians()
+    }
+}
+
+impl From<Radians> for Degrees {
+    fn from(value: Radians) -> Self {
+        value.to_degrees()
+    }
+}
+
+impl Display for Radians {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} rad", self.0)
+    }
+}
+
+impl Display for Degrees {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}°", self.0)
+    }
+}
+
+macro_rules! angle_ops {
+    ($angle:ident) => {
+        impl Add for $angle {
+            type Output = $angle;
+            fn add(self, rhs: Self) -> Self::Output {
+                $angle(self.0 + rhs.0)
+            }
+        }
+
+        impl AddAssign for $angle {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl Sub for $angle {
+            type Output = $angle;
+            fn sub(self, rhs: Self) -> Self::Output {
+                $angle(self.0 - rhs.0)
+            }
+        }
+
+        impl SubAssign for $angle {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl Mul<f32> for $angle {
+            type Output = $angle;
+            fn mul(self, rhs: f32) -> Self::Output {
+                $angle(self.0 * rhs)
+            }
+        }
+
+        impl Div<f32> for $angle {
+            type Output = $angle;
+            fn div(self, rhs: f32) -> Self::Output {
+                $angle(self.0 / rhs)
+            }
+        }
+
+        impl Neg for $angle {
+            type Output = $angle;
+            fn neg(self) -> Self::Output {
+                $angle(-self.0)
+            }
+        }
+    };
+}
+
+angle_ops!(Radians);
+angle_ops!(Degrees);
+
+#[cfg(test)]
+mod tests;
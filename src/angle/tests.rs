@@ -0,0 +1,55 @@
+use super::*;
+
+fn approx(a: f32, b: f32) -> bool {
+    (a - b).abs() < 1e-5
+}
+
+#[test]
+fn conversions() {
+    let radians: Radians = Degrees(180.).into();
+    let degrees: Degrees = Radians(PI / 2.).into();
+
+    assert!(approx(radians.0, PI));
+    assert!(approx(degrees.0, 90.));
+}
+
+#[test]
+fn arithmetic() {
+    let mut angle = Degrees(30.) + Degrees(15.) - Degrees(5.);
+    angle += Degrees(10.);
+    angle -= Degrees(20.);
+
+    assert_eq!(angle, Degrees(30.));
+    assert_eq!(angle * 2., Degrees(60.));
+    assert_eq!(angle / 3., Degrees(10.));
+    assert_eq!(-Radians(1.), Radians(-1.));
+}
+
+#[test]
+fn normalize_radians() {
+    assert!(approx(Radians(3. * PI / 2.).normalize().0, -PI / 2.));
+    assert!(approx(Radians(-PI).normalize().0, PI));
+    assert!(approx(Radians(PI).normalize().0, PI));
+    assert!(approx(
+        Radians(-PI / 2.).normalize_positive().0,
+        3. * PI / 2.
+    ));
+    assert!(approx(Radians(5. * PI).normalize_positive().0, PI));
+}
+
+#[test]
+fn normalize_degrees() {
+    assert_eq!(Degrees(270.).normalize(), Degrees(-90.));
+    assert_eq!(Degrees(-180.).normalize(), Degrees(180.));
+    assert_eq!(Degrees(-90.).normalize_positive(), Degrees(270.));
+    assert_eq!(Degrees(720.).normalize_positive(), Degrees(0.));
+}
+
+#[test]
+fn sin_cos() {
+    let (sin, cos) = Degrees(30.).sin_cos();
+
+    assert!(approx(sin, 0.5));
+    assert!(approx(cos, 3f32.sqrt() / 2.));
+    assert_eq!(Radians(0.).sin_cos(), (0., 1.));
+}
@@ -0,0 +1,281 @@
+use crate::prelude::{Matrix3x3, Radians, Vector2, Vector3};
+
+mod system;
+
+pub use system::{Axis, CoordinateSystem, Handedness};
+
+/// Which of the two spherical angles is called θ.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AngleConvention {
+    /// ISO 80000-2: θ is the polar angle, φ the azimuth.
+    Physics,
+    /// θ is the azimuth, φ the polar angle.
+    Math,
+}
+
+/// Axis that spherical polar angles and cylindrical heights are measured along.
+///
+/// With `Z` the azimuth runs from +X towards +Y; with `Y` it runs from +Z
+/// towards +X, so both frames are right-handed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum UpAxis {
+    Y,
+    Z,
+}
+
+impl UpAxis {
+    /// Cartesian `v` expressed in the Z-up frame.
+    fn to_local(self, v: Vector3) -> Vector3 {
+        match self {
+            UpAxis::Z => v,
+            UpAxis::Y => Vector3::new(v.z, v.x, v.y),
+        }
+    }
+
+    /// Z-up frame vector `v` expressed in this frame.
+    fn to_world(self, v: Vector3) -> Vector3 {
+        match self {
+            UpAxis::Z => v,
+            UpAxis::Y => Vector3::new(v.y, v.z, v.x),
+        }
+    }
+
+    /// Reorders the rows of a Jacobian of Z-up Cartesian coordinates.
+    fn rows_to_world(self, m: Matrix3x3) -> Matrix3x3 {
+        Matrix3x3::from_cols(
+            self.to_world(m.col(0)),
+            self.to_world(m.col(1)),
+            self.to_world(m.col(2)),
+        )
+    }
+
+    /// Reorders the columns of a Jacobian with respect to Z-up Cartesian coordinates.
+    fn cols_to_world(self, m: Matrix3x3) -> Matrix3x3 {
+        self.rows_to_world(m.transpose()).transpose()
+    }
+}
+
+/// Spherical coordinates with the polar angle measured from the up axis.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Spherical {
+    pub radius: f32,
+    pub polar: Radians,
+    pub azimuth: Radians,
+}
+
+/// Cylindrical coordinates around the up axis.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Cylindrical {
+    pub radius: f32,
+    pub azimuth: Radians,
+    pub height: f32,
+}
+
+/// Plane polar coordinates, with the angle measured from +X towards +Y.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Polar {
+    pub radius: f32,
+    pub angle: Radians,
+}
+
+impl Spherical {
+    pub fn new(radius: f32, polar: impl Into<Radians>, azimuth: impl Into<Radians>) -> Self {
+        Spherical {
+            radius,
+            polar: polar.into(),
+            azimuth: azimuth.into(),
+        }
+    }
+
+    /// Builds from a `(θ, φ)` pair named according to `convention`.
+    pub fn from_theta_phi(
+        radius: f32,
+        theta: impl Into<Radians>,
+        phi: impl Into<Radians>,
+        convention: AngleConvention,
+    ) -> Spherical {
+        match convention {
+            AngleConvention::Physics => Spherical::new(radius, theta, phi),
+            AngleConvention::Math => Spherical::new(radius, phi, theta),
+        }
+    }
+
+    /// The `(θ, φ)` pair named according to `convention`.
+    pub fn theta_phi(self, convention: AngleConvention) -> (Radians, Radians) {
+        match convention {
+            AngleConvention::Physics => (self.polar, self.azimuth),
+            AngleConvention::Math => (self.azimuth, self.polar),
+        }
+    }
+
+    /// Polar angle in `[0, π]` and azimuth in `(-π, π]`. The origin maps to
+    /// all-zero coordinates.
+    pub fn from_vector3(v: Vector3, up: UpAxis) -> Spherical {
+        let local = up.to_local(v);
+        let radius = local.magnitude();
+        let horizontal = Vector2::new(local.x, local.y).magnitude();
+        Spherical {
+            radius,
+            polar: Radians(horizontal.atan2(local.z)),
+            azimuth: Radians(local.y.atan2(local.x)),
+        }
+    }
+
+    pub fn to_vector3(self, up: UpAxis) -> Vector3 {
+        let (sin_p, cos_p) = self.polar.sin_cos();
+        let (sin_a, cos_a) = self.azimuth.sin_cos();
+        up.to_world(Vector3::new(sin_p * cos_a, sin_p * sin_a, cos_p) * self.radius)
+    }
+
+    /// Derivative of the Cartesian position with respect to
+    /// `(radius, polar, azimuth)`, one column per coordinate.
+    pub fn jacobian(self, up: UpAxis) -> Matrix3x3 {
+        let r = self.radius;
+        let (sin_p, cos_p) = self.polar.sin_cos();
+        let (sin_a, cos_a) = self.azimuth.sin_cos();
+        up.rows_to_world(Matrix3x3::new(
+            sin_p * cos_a,
+            r * cos_p * cos_a,
+            -r * sin_p * sin_a,
+            sin_p * sin_a,
+            r * cos_p * sin_a,
+            r * sin_p * cos_a,
+            cos_p,
+            -r * sin_p,
+            0.,
+        ))
+    }
+
+    /// Derivative of `(radius, polar, azimuth)` with respect to the Cartesian
+    /// position. Singular on the up axis, where the result is not finite.
+    pub fn inverse_jacobian(self, up: UpAxis) -> Matrix3x3 {
+        let r = self.radius;
+        let (sin_p, cos_p) = self.polar.sin_cos();
+        let (sin_a, cos_a) = self.azimuth.sin_cos();
+        up.cols_to_world(Matrix3x3::new(
+            sin_p * cos_a,
+            sin_p * sin_a,
+            cos_p,
+            cos_p * cos_a / r,
+            cos_p * sin_a / r,
+            -sin_p / r,
+            -sin_a / (r * sin_p),
+            cos_a / (r * sin_p),
+            0.,
+        ))
+    }
+}
+
+impl Cylindrical {
+    pub fn new(radius: f32, azimuth: impl Into<Radians>, height: f32) -> Self {
+        Cylindrical {
+            radius,
+            azimuth: azimuth.into(),
+            height,
+        }
+    }
+
+    pub fn from_vector3(v: Vector3, up: UpAxis) -> Cylindrical {
+        let local = up.to_local(v);
+        let polar = Polar::from_vector2(Vector2::new(local.x, local.y));
+        Cylindrical {
+            radius: polar.radius,
+            azimuth: polar.angle,
+            height: local.z,
+        }
+    }
+
+    pub fn to_vector3(self, up: UpAxis) -> Vector3 {
+        let (sin, cos) = self.azimuth.sin_cos();
+        up.to_world(Vector3::new(
+            self.radius * cos,
+            self.radius * sin,
+            self.height,
+        ))
+    }
+
+    /// Derivative of the Cartesian position with respect to
+    /// `(radius, azimuth, height)`, one column per coordinate.
+    pub fn jacobian(self, up: UpAxis) -> Matrix3x3 {
+        let (sin, cos) = self.azimuth.sin_cos();
+        let r = self.radius;
+        up.rows_to_world(Matrix3x3::new(
+            cos,
+            -r * sin,
+            0.,
+            sin,
+            r * cos,
+            0.,
+            0.,
+            0.,
+            1.,
+        ))
+    }
+
+    /// Derivative of `(radius, azimuth, height)` with respect to the Cartesian
+    /// position. Singular on the up axis.
+    pub fn inverse_jacobian(self, up: UpAxis) -> Matrix3x3 {
+        let (sin, cos) = self.azimuth.sin_cos();
+        let r = self.radius;
+        up.cols_to_world(Matrix3x3::new(
+            cos,
+            sin,
+            0.,
+            -sin / r,
+            cos / r,
+            0.,
+            0.,
+            0.,
+            1.,
+        ))
+    }
+}
+
+impl Polar {
+    pub fn new(radius: f32, angle: impl Into<Radians>) -> Self {
+        Polar {
+            radius,
+            angle: angle.into(),
+        }
+    }
+
+    /// Angle in `(-π, π]`.
+    pub fn from_vector2(v: Vector2) -> Polar {
+        Polar {
+            radius: v.magnitude(),
+            angle: Radians(v.y.atan2(v.x)),
+        }
+    }
+
+    pub fn to_vector2(self) -> Vector2 {
+        let (sin, cos) = self.angle.sin_cos();
+        Vector2::new(self.radius * cos, self.radius * sin)
+    }
+
+    /// Derivative of `(x, y)` with respect to `(radius, angle)` in the upper
+    /// left 2x2 block; the third row and column are the identity.
+    pub fn jacobian(self) -> Matrix3x3 {
+        Cylindrical::new(self.radius, self.angle, 0.).jacobian(UpAxis::Z)
+    }
+
+    /// Derivative of `(radius, angle)` with respect to `(x, y)`, laid out as
+    /// [`Polar::jacobian`].
+    pub fn inverse_jacobian(self) -> Matrix3x3 {
+        Cylindrical::new(self.radius, self.angle, 0.).inverse_jacobian(UpAxis::Z)
+    }
+}
+
+impl From<Vector2> for Polar {
+    fn from(value: Vector2) -> Self {
+        Polar::from_vector2(value)
+    }
+}
+
+impl From<Polar> for Vector2 {
+    fn from(value: Polar) -> Self {
+        value.to_vector2()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,147 @@
+use crate::matrices::product;
+use crate::prelude::{MathError, Matrix3x3, Quaternion, Vector3};
+
+/// A signed coordinate axis.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Axis {
+    PositiveX,
+    NegativeX,
+    PositiveY,
+    NegativeY,
+    PositiveZ,
+    NegativeZ,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Handedness {
+    Left,
+    Right,
+}
+
+/// Describes which axis of a tool's coordinates points right, up and forward.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CoordinateSystem {
+    right: Axis,
+    up: Axis,
+    forward: Axis,
+}
+
+impl Axis {
+    pub fn to_vector(self) -> Vector3 {
+        match self {
+            Axis::PositiveX => Vector3::new(1., 0., 0.),
+            Axis::NegativeX => Vector3::new(-1., 0., 0.),
+            Axis::PositiveY => Vector3::new(0., 1., 0.),
+            Axis::NegativeY => Vector3::new(0., -1., 0.),
+            Axis::PositiveZ => Vector3::new(0., 0., 1.),
+            Axis::NegativeZ => Vector3::new(0., 0., -1.),
+        }
+    }
+}
+
+impl CoordinateSystem {
+    /// Y up, forward along -Z (OpenGL, glTF, Maya).
+    pub const Y_UP_RIGHT_HANDED: CoordinateSystem = CoordinateSystem {
+        right: Axis::PositiveX,
+        up: Axis::PositiveY,
+        forward: Axis::NegativeZ,
+    };
+
+    /// Z up, forward along +Y (Blender, 3ds Max).
+    pub const Z_UP_RIGHT_HANDED: CoordinateSystem = CoordinateSystem {
+        right: Axis::PositiveX,
+        up: Axis::PositiveZ,
+        forward: Axis::PositiveY,
+    };
+
+    /// Y up, forward along +Z (Unity, Direct3D).
+    pub const Y_UP_LEFT_HANDED: CoordinateSystem = CoordinateSystem {
+        right: Axis::PositiveX,
+        up: Axis::PositiveY,
+        forward: Axis::PositiveZ,
+    };
+
+    /// Z up, forward along +X (Unreal).
+    pub const Z_UP_LEFT_HANDED: CoordinateSystem = CoordinateSystem {
+        right: Axis::PositiveY,
+        up: Axis::PositiveZ,
+        forward: Axis::PositiveX,
+    };
+
+    /// Fails when two of the axes are parallel.
+    pub fn new(right: Axis, up: Axis, forward: Axis) -> Result<Self, MathError> {
+        let system = CoordinateSystem { right, up, forward };
+        if system.basis().determinant() == 0. {
+            return Err(MathError::Degenerate);
+        }
+        Ok(system)
+    }
+
+    pub fn right(self) -> Axis {
+        self.right
+    }
+
+    pub fn up(self) -> Axis {
+        self.up
+    }
+
+    pub fn forward(self) -> Axis {
+        self.forward
+    }
+
+    /// Right-handed systems have `right × up` pointing backwards.
+    pub fn handedness(self) -> Handedness {
+        let r = self.right.to_vector();
+        let u = self.up.to_vector();
+        if r.cross(u).dot(self.forward.to_vector()) < 0. {
+            Handedness::Right
+        } else {
+            Handedness::Left
+        }
+    }
+
+    /// Columns are the right, up and forward directions in this system.
+    fn basis(self) -> Matrix3x3 {
+        Matrix3x3::from_cols(
+            self.right.to_vector(),
+            self.up.to_vector(),
+            self.forward.to_vector(),
+        )
+    }
+
+    /// Matrix taking coordinates in `self` to coordinates in `to`. Its
+    /// determinant is -1 when the handedness differs.
+    pub fn change_of_basis(self, to: CoordinateSystem) -> Matrix3x3 {
+        product(to.basis(), self.basis().transpose())
+    }
+
+    /// [`CoordinateSystem::change_of_basis`] as a rotation. Systems of
+    /// different handedness are related by a reflection, which has no
+    /// quaternion.
+    pub fn change_of_basis_rotation(self, to: CoordinateSystem) -> Result<Quaternion, MathError> {
+        if self.handedness() != to.handedness() {
+            return Err(MathError::HandednessMismatch);
+        }
+        Ok(Quaternion::from_matrix(self.change_of_basis(to)))
+    }
+
+    pub fn convert_vector(self, to: CoordinateSystem, v: Vector3) -> Vector3 {
+        self.change_of_basis(to) * v
+    }
+
+    /// Re-expresses a rotation given in `self` coordinates so that it performs
+    /// the same physical rotation in `to` coordinates.
+    pub fn convert_quaternion(self, to: CoordinateSystem, q: Quaternion) -> Quaternion {
+        let m = self.change_of_basis(to);
+        // The vector part is a pseudovector: it flips under reflections.
+        let sign = m.determinant().signum();
+        Quaternion::new(q.scalar(), m * q.vector() * sign)
+    }
+
+    /// Re-expresses a linear map given in `self` coordinates in `to`
+    /// coordinates.
+    pub fn convert_matrix(self, to: CoordinateSystem, m: Matrix3x3) -> Matrix3x3 {
+        let c = self.change_of_basis(to);
+        product(product(c, m), c.transpose())
+    }
+}
@@ -0,0 +1,216 @@
+use super::*;
+
+use crate::prelude::{MathError, Quaternion};
+
+use std::f32::consts::{FRAC_PI_2, PI};
+
+fn approx_eq(a: Vector3, b: Vector3, eps: f32) -> bool {
+    (a - b).magnitude() <= eps
+}
+
+fn is_identity(m: Matrix3x3) -> bool {
+    m.data
+        .iter()
+        .zip(Matrix3x3::identity().data.iter())
+        .all(|(a, b)| (a - b).abs() < 1e-4)
+}
+
+#[test]
+fn spherical_roundtrip() {
+    let v = Vector3::new(1., -2., 3.);
+    for up in [UpAxis::Y, UpAxis::Z] {
+        let s = Spherical::from_vector3(v, up);
+
+        assert!(approx_eq(s.to_vector3(up), v, 1e-5));
+        assert!((0. ..=PI).contains(&s.polar.0));
+    }
+    assert_eq!(
+        Spherical::from_vector3(Vector3::default(), UpAxis::Z),
+        Spherical::default()
+    );
+}
+
+#[test]
+fn spherical_up_axis() {
+    let north = Spherical::new(2., Radians(0.), Radians(0.));
+    let equator = Spherical::new(1., Radians(FRAC_PI_2), Radians(0.));
+
+    assert!(approx_eq(
+        north.to_vector3(UpAxis::Z),
+        Vector3::new(0., 0., 2.),
+        1e-6
+    ));
+    assert!(approx_eq(
+        north.to_vector3(UpAxis::Y),
+        Vector3::new(0., 2., 0.),
+        1e-6
+    ));
+    assert!(approx_eq(
+        equator.to_vector3(UpAxis::Z),
+        Vector3::new(1., 0., 0.),
+        1e-6
+    ));
+    assert!(approx_eq(
+        equator.to_vector3(UpAxis::Y),
+        Vector3::new(0., 0., 1.),
+        1e-6
+    ));
+}
+
+#[test]
+fn spherical_conventions() {
+    let physics =
+        Spherical::from_theta_phi(1., Radians(0.3), Radians(1.2), AngleConvention::Physics);
+    let math = Spherical::from_theta_phi(1., Radians(1.2), Radians(0.3), AngleConvention::Math);
+
+    assert_eq!(physics, math);
+    assert_eq!(physics.polar, Radians(0.3));
+    assert_eq!(
+        math.theta_phi(AngleConvention::Math),
+        (Radians(1.2), Radians(0.3))
+    );
+}
+
+#[test]
+fn spherical_jacobians() {
+    let h = 1e-3;
+    let s = Spherical::new(2., Radians(0.7), Radians(-1.1));
+    for up in [UpAxis::Y, UpAxis::Z] {
+        let j = s.jacobian(up);
+        let columns = [
+            (Spherical::new(s.radius + h, s.polar, s.azimuth).to_vector3(up)
+                - Spherical::new(s.radius - h, s.polar, s.azimuth).to_vector3(up))
+                / (2. * h),
+            (Spherical::new(s.radius, Radians(s.polar.0 + h), s.azimuth).to_vector3(up)
+                - Spherical::new(s.radius, Radians(s.polar.0 - h), s.azimuth).to_vector3(up))
+                / (2. * h),
+            (Spherical::new(s.radius, s.polar, Radians(s.azimuth.0 + h)).to_vector3(up)
+                - Spherical::new(s.radius, s.polar, Radians(s.azimuth.0 - h)).to_vector3(up))
+                / (2. * h),
+        ];
+
+        for (i, numerical) in columns.iter().enumerate() {
+            assert!(approx_eq(j.col(i), *numerical, 1e-3));
+        }
+        assert!(is_identity(s.inverse_jacobian(up) * j));
+    }
+}
+
+#[test]
+fn cylindrical_roundtrip_and_jacobians() {
+    let v = Vector3::new(-1., 2., 0.5);
+    for up in [UpAxis::Y, UpAxis::Z] {
+        let c = Cylindrical::from_vector3(v, up);
+
+        assert!(approx_eq(c.to_vector3(up), v, 1e-5));
+        assert!(is_identity(c.inverse_jacobian(up) * c.jacobian(up)));
+    }
+
+    let c = Cylindrical::new(2., Radians(FRAC_PI_2), 3.);
+    assert!(approx_eq(
+        c.to_vector3(UpAxis::Z),
+        Vector3::new(0., 2., 3.),
+        1e-6
+    ));
+    assert!(approx_eq(
+        c.to_vector3(UpAxis::Y),
+        Vector3::new(2., 3., 0.),
+        1e-6
+    ));
+    assert!(approx_eq(
+        c.jacobian(UpAxis::Z).col(1),
+        Vector3::new(-2., 0., 0.),
+        1e-6
+    ));
+}
+
+#[test]
+fn polar_roundtrip_and_jacobians() {
+    let v = Vector2::new(-3., -4.);
+    let p = Polar::from(v);
+    let back: Vector2 = p.into();
+
+    assert_eq!(p.radius, 5.);
+    assert!((back - v).magnitude() < 1e-5);
+    assert!(is_identity(p.inverse_jacobian() * p.jacobian()));
+    assert!((Polar::new(1., Radians(PI)).to_vector2() - Vector2::new(-1., 0.)).magnitude() < 1e-6);
+}
+
+const SYSTEMS: [CoordinateSystem; 4] = [
+    CoordinateSystem::Y_UP_RIGHT_HANDED,
+    CoordinateSystem::Z_UP_RIGHT_HANDED,
+    CoordinateSystem::Y_UP_LEFT_HANDED,
+    CoordinateSystem::Z_UP_LEFT_HANDED,
+];
+
+#[test]
+fn coordinate_system_handedness() {
+    assert_eq!(
+        CoordinateSystem::Y_UP_RIGHT_HANDED.handedness(),
+        Handedness::Right
+    );
+    assert_eq!(
+        CoordinateSystem::Z_UP_RIGHT_HANDED.handedness(),
+        Handedness::Right
+    );
+    assert_eq!(
+        CoordinateSystem::Y_UP_LEFT_HANDED.handedness(),
+        Handedness::Left
+    );
+    assert_eq!(
+        CoordinateSystem::Z_UP_LEFT_HANDED.handedness(),
+        Handedness::Left
+    );
+    assert_eq!(
+        CoordinateSystem::new(Axis::PositiveX, Axis::NegativeX, Axis::PositiveZ),
+        Err(MathError::Degenerate)
+    );
+}
+
+#[test]
+fn convert_semantic_directions() {
+    for from in SYSTEMS {
+        for to in SYSTEMS {
+            let m = from.change_of_basis(to);
+
+            assert_eq!(m * from.up().to_vector(), to.up().to_vector());
+            assert_eq!(m * from.right().to_vector(), to.right().to_vector());
+            assert_eq!(
+                from.convert_vector(to, from.forward().to_vector()),
+                to.forward().to_vector()
+            );
+        }
+    }
+}
+
+#[test]
+fn change_of_basis_rotation() {
+    let blender = CoordinateSystem::Z_UP_RIGHT_HANDED;
+    let gl = CoordinateSystem::Y_UP_RIGHT_HANDED;
+    let q = blender.change_of_basis_rotation(gl).unwrap();
+    let v = Vector3::new(1., 2., 3.);
+
+    assert!(approx_eq(q.rotate(v), blender.convert_vector(gl, v), 1e-6));
+    assert_eq!(
+        blender.change_of_basis_rotation(CoordinateSystem::Y_UP_LEFT_HANDED),
+        Err(MathError::HandednessMismatch)
+    );
+}
+
+#[test]
+fn convert_rotations_consistently() {
+    let q = Quaternion::from_axis_angle(Vector3::new(1., 2., 0.5), Radians(0.8));
+    let m = q.to_matrix();
+    let v = Vector3::new(0.3, -1., 2.);
+    for from in SYSTEMS {
+        for to in SYSTEMS {
+            let converted_q = from.convert_quaternion(to, q);
+            let converted_m = from.convert_matrix(to, m);
+            let expected = from.convert_vector(to, q.rotate(v));
+            let v_to = from.convert_vector(to, v);
+
+            assert!(approx_eq(converted_q.rotate(v_to), expected, 1e-5));
+            assert!(approx_eq(converted_m * v_to, expected, 1e-5));
+        }
+    }
+}
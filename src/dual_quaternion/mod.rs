@@ -0,0 +1,156 @@
+use std::ops::{Add, Mul};
+
+use crate::prelude::{Quaternion, Twist, Vector3, SE3, SO3};
+
+/// Rigid transform `real + ε·dual`, where `real` is the rotation and
+/// `dual = ½·t·real` encodes the translation `t`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DualQuaternion {
+    real: Quaternion,
+    dual: Quaternion,
+}
+
+impl Default for DualQuaternion {
+    fn default() -> Self {
+        DualQuaternion::identity()
+    }
+}
+
+impl DualQuaternion {
+    pub fn new(real: Quaternion, dual: Quaternion) -> Self {
+        DualQuaternion { real, dual }
+    }
+
+    pub fn identity() -> DualQuaternion {
+        DualQuaternion::new(
+            Quaternion::identity(),
+            Quaternion::new(0., Vector3::default()),
+        )
+    }
+
+    /// Transform that rotates by `rotation` and then translates by `translation`.
+    pub fn from_rotation_translation(rotation: Quaternion, translation: Vector3) -> DualQuaternion {
+        let real = rotation.normalized();
+        let dual = Quaternion::new(0., translation) * real * 0.5;
+        DualQuaternion::new(real, dual)
+    }
+
+    pub fn from_se3(transform: SE3) -> DualQuaternion {
+        DualQuaternion::from_rotation_translation(
+            transform.rotation.quaternion(),
+            transform.translation,
+        )
+    }
+
+    pub fn to_se3(self) -> SE3 {
+        SE3::new(SO3::from_quaternion(self.real), self.translation())
+    }
+
+    pub fn real(self) -> Quaternion {
+        self.real
+    }
+
+    pub fn dual(self) -> Quaternion {
+        self.dual
+    }
+
+    pub fn rotation(self) -> Quaternion {
+        self.real
+    }
+
+    pub fn translation(self) -> Vector3 {
+        (self.dual * self.real.conjugate() * 2.).vector()
+    }
+
+    /// Quaternion conjugate of both parts. For unit dual quaternions this is
+    /// the inverse transform.
+    pub fn conjugate(self) -> DualQuaternion {
+        DualQuaternion::new(self.real.conjugate(), self.dual.conjugate())
+    }
+
+    /// `real - ε·dual`.
+    pub fn dual_conjugate(self) -> DualQuaternion {
+        DualQuaternion::new(self.real, self.dual * -1.)
+    }
+
+    /// Quaternion and dual conjugate combined, used to transform points.
+    pub fn combined_conjugate(self) -> DualQuaternion {
+        DualQuaternion::new(self.real.conjugate(), self.dual.conjugate() * -1.)
+    }
+
+    /// Unit dual quaternion: unit `real` part with `dual` orthogonal to it.
+    pub fn normalized(self) -> DualQuaternion {
+        let n = self.real.dot(self.real).sqrt();
+        if n == 0. {
+            return self;
+        }
+        let real = self.real * (1. / n);
+        let dual = self.dual * (1. / n);
+        let dual = dual - real * real.dot(dual);
+        DualQuaternion::new(real, dual)
+    }
+
+    pub fn transform_point(self, p: Vector3) -> Vector3 {
+        self.real.rotate(p) + self.translation()
+    }
+
+    pub fn transform_vector(self, v: Vector3) -> Vector3 {
+        self.real.rotate(v)
+    }
+
+    /// Screw linear interpolation: constant-speed motion along the screw
+    /// connecting `self` (`t = 0`) and `other` (`t = 1`).
+    pub fn sclerp(self, other: DualQuaternion, t: f32) -> DualQuaternion {
+        let delta = (self.conjugate() * other).to_se3().log();
+        let step = Twist::new(delta.linear * t, delta.angular * t);
+        self * DualQuaternion::from_se3(SE3::exp(step))
+    }
+
+    /// Dual-quaternion linear blending of weighted transforms. Every transform is
+    /// flipped into the hemisphere of the first one before blending.
+    pub fn blend(transforms: &[(DualQuaternion, f32)]) -> DualQuaternion {
+        let Some((pivot, _)) = transforms.first() else {
+            return DualQuaternion::identity();
+        };
+
+        let sum = transforms.iter().fold(
+            DualQuaternion::new(Quaternion::default(), Quaternion::default()),
+            |acc, (dq, w)| {
+                let w = if dq.real.dot(pivot.real) < 0. { -w } else { *w };
+                acc + *dq * w
+            },
+        );
+
+        if sum.real.dot(sum.real) == 0. {
+            return DualQuaternion::identity();
+        }
+        sum.normalized()
+    }
+}
+
+impl Add for DualQuaternion {
+    type Output = DualQuaternion;
+    fn add(self, rhs: Self) -> Self::Output {
+        DualQuaternion::new(self.real + rhs.real, self.dual + rhs.dual)
+    }
+}
+
+impl Mul for DualQuaternion {
+    type Output = DualQuaternion;
+    fn mul(self, rhs: Self) -> Self::Output {
+        DualQuaternion::new(
+            self.real * rhs.real,
+            self.real * rhs.dual + self.dual * rhs.real,
+        )
+    }
+}
+
+impl Mul<f32> for DualQuaternion {
+    type Output = DualQuaternion;
+    fn mul(self, rhs: f32) -> Self::Output {
+        DualQuaternion::new(self.real * rhs, self.dual * rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,155 @@
+use super::*;
+use crate::prelude::Radians;
+
+use std::f32::consts::FRAC_PI_2;
+
+fn vec_approx_eq(a: Vector3, b: Vector3, eps: f32) -> bool {
+    (a - b).magnitude() <= eps
+}
+
+fn sample() -> DualQuaternion {
+    DualQuaternion::from_rotation_translation(
+        Quaternion::from_axis_angle(Vector3::new(0., 0., 1.), Radians(FRAC_PI_2)),
+        Vector3::new(1., 2., 3.),
+    )
+}
+
+#[test]
+fn create_identity() {
+    let p = Vector3::new(1., 2., 3.);
+
+    assert_eq!(DualQuaternion::default(), DualQuaternion::identity());
+    assert_eq!(DualQuaternion::identity().transform_point(p), p);
+}
+
+#[test]
+fn rotation_and_translation() {
+    let dq = sample();
+
+    assert!(vec_approx_eq(
+        dq.translation(),
+        Vector3::new(1., 2., 3.),
+        1e-6
+    ));
+    assert!(vec_approx_eq(
+        dq.transform_point(Vector3::new(1., 0., 0.)),
+        Vector3::new(1., 3., 3.),
+        1e-6
+    ));
+    assert!(vec_approx_eq(
+        dq.transform_vector(Vector3::new(1., 0., 0.)),
+        Vector3::new(0., 1., 0.),
+        1e-6
+    ));
+}
+
+#[test]
+fn multiply_composes_transforms() {
+    let a = sample();
+    let b = DualQuaternion::from_rotation_translation(
+        Quaternion::from_axis_angle(Vector3::new(1., 0., 0.), Radians(0.4)),
+        Vector3::new(-2., 0., 1.),
+    );
+    let p = Vector3::new(0.5, -1., 2.);
+
+    assert!(vec_approx_eq(
+        (a * b).transform_point(p),
+        a.transform_point(b.transform_point(p)),
+        1e-5
+    ));
+}
+
+#[test]
+fn conjugates() {
+    let dq = sample();
+    let p = Vector3::new(0.5, -1., 2.);
+    let dual = dq.dual_conjugate();
+    let combined = dq.combined_conjugate();
+
+    assert!(vec_approx_eq(
+        dq.conjugate().transform_point(dq.transform_point(p)),
+        p,
+        1e-5
+    ));
+    assert_eq!(dual.real(), dq.real());
+    assert_eq!(dual.dual(), dq.dual() * -1.);
+    assert_eq!(combined.real(), dq.real().conjugate());
+    assert_eq!(combined.dual(), dq.dual().conjugate() * -1.);
+}
+
+#[test]
+fn normalize() {
+    let dq = (sample() * 3.).normalized();
+
+    assert!((dq.real().dot(dq.real()) - 1.).abs() < 1e-6);
+    assert!(dq.real().dot(dq.dual()).abs() < 1e-6);
+    assert!(vec_approx_eq(
+        dq.translation(),
+        Vector3::new(1., 2., 3.),
+        1e-5
+    ));
+}
+
+#[test]
+fn sclerp_endpoints_and_midpoint() {
+    let a = DualQuaternion::identity();
+    let b = sample();
+    let p = Vector3::new(1., 0., 0.);
+
+    assert!(vec_approx_eq(a.sclerp(b, 0.).transform_point(p), p, 1e-5));
+    assert!(vec_approx_eq(
+        a.sclerp(b, 1.).transform_point(p),
+        b.transform_point(p),
+        1e-5
+    ));
+
+    let half = a.sclerp(b, 0.5);
+    assert!(vec_approx_eq(
+        (half * half).transform_point(p),
+        b.transform_point(p),
+        1e-5
+    ));
+}
+
+#[test]
+fn sclerp_pure_translation() {
+    let a = DualQuaternion::identity();
+    let b =
+        DualQuaternion::from_rotation_translation(Quaternion::identity(), Vector3::new(4., 0., 0.));
+
+    assert!(vec_approx_eq(
+        a.sclerp(b, 0.25).translation(),
+        Vector3::new(1., 0., 0.),
+        1e-6
+    ));
+}
+
+#[test]
+fn blend_weighted_transforms() {
+    let a =
+        DualQuaternion::from_rotation_translation(Quaternion::identity(), Vector3::new(2., 0., 0.));
+    let b =
+        DualQuaternion::from_rotation_translation(Quaternion::identity(), Vector3::new(0., 2., 0.));
+    let blended = DualQuaternion::blend(&[(a, 0.5), (b, 0.5)]);
+
+    assert!(vec_approx_eq(
+        blended.translation(),
+        Vector3::new(1., 1., 0.),
+        1e-6
+    ));
+    assert_eq!(DualQuaternion::blend(&[]), DualQuaternion::identity());
+}
+
+#[test]
+fn blend_handles_antipodal_rotations() {
+    let a = sample();
+    let flipped = a * -1.;
+    let blended = DualQuaternion::blend(&[(a, 0.5), (flipped, 0.5)]);
+    let p = Vector3::new(1., 0., 0.);
+
+    assert!(vec_approx_eq(
+        blended.transform_point(p),
+        a.transform_point(p),
+        1e-5
+    ));
+}
@@ -0,0 +1,33 @@
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum MathError {
+    /// The matrix has no inverse, so the system has no unique solution.
+    Singular,
+    /// Cholesky factorization requires a symmetric positive-definite matrix.
+    NotPositiveDefinite,
+    /// The input is zero, collinear, coplanar or otherwise lacks the
+    /// dimensions the operation needs.
+    Degenerate,
+    /// The coordinate systems differ in handedness, so no rotation relates them.
+    HandednessMismatch,
+    /// A slice does not hold as many items as the operation expects.
+    LengthMismatch,
+    /// A parameter lies outside the range the operation documents.
+    OutOfRange,
+}
+
+impl Display for MathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MathError::Singular => write!(f, "matrix is singular"),
+            MathError::NotPositiveDefinite => write!(f, "matrix is not positive definite"),
+            MathError::Degenerate => write!(f, "input is degenerate"),
+            MathError::HandednessMismatch => write!(f, "coordinate systems differ in handedness"),
+            MathError::LengthMismatch => write!(f, "input length does not match"),
+            MathError::OutOfRange => write!(f, "parameter is out of range"),
+        }
+    }
+}
+
+impl std::error::Error for MathError {}
@@ -0,0 +1,103 @@
+use super::{PointQuery, Transform};
+use crate::prelude::{Matrix3x3, Vector3};
+
+/// Axis-aligned bounding box.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Aabb {
+    pub min: Vector3,
+    pub max: Vector3,
+}
+
+impl Aabb {
+    /// Box spanned by two opposite corners in any order.
+    pub fn new(a: Vector3, b: Vector3) -> Self {
+        Aabb {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    /// Smallest box containing `points`, or `None` when there are none.
+    pub fn from_points(points: &[Vector3]) -> Option<Self> {
+        let (&first, rest) = points.split_first()?;
+        Some(rest.iter().fold(Aabb::new(first, first), |aabb, p| Aabb {
+            min: aabb.min.min(*p),
+            max: aabb.max.max(*p),
+        }))
+    }
+
+    pub fn from_center_half_extents(center: Vector3, half_extents: Vector3) -> Self {
+        Aabb::new(center - half_extents, center + half_extents)
+    }
+
+    pub fn center(self) -> Vector3 {
+        (self.min + self.max) * 0.5
+    }
+
+    pub fn half_extents(self) -> Vector3 {
+        (self.max - self.min) * 0.5
+    }
+
+    pub fn size(self) -> Vector3 {
+        self.max - self.min
+    }
+
+    pub fn surface_area(self) -> f32 {
+        let s = self.size();
+        2. * (s.x * s.y + s.y * s.z + s.z * s.x)
+    }
+
+    /// Smallest box containing both boxes.
+    pub fn merge(self, other: Aabb) -> Aabb {
+        Aabb {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    pub fn corners(self) -> [Vector3; 8] {
+        let (a, b) = (self.min, self.max);
+        [
+            Vector3::new(a.x, a.y, a.z),
+            Vector3::new(b.x, a.y, a.z),
+            Vector3::new(a.x, b.y, a.z),
+            Vector3::new(b.x, b.y, a.z),
+            Vector3::new(a.x, a.y, b.z),
+            Vector3::new(b.x, a.y, b.z),
+            Vector3::new(a.x, b.y, b.z),
+            Vector3::new(b.x, b.y, b.z),
+        ]
+    }
+}
+
+impl PointQuery for Aabb {
+    fn contains_point(&self, p: Vector3) -> bool {
+        p.x >= self.min.x
+            && p.x <= self.max.x
+            && p.y >= self.min.y
+            && p.y <= self.max.y
+            && p.z >= self.min.z
+            && p.z <= self.max.z
+    }
+
+    fn closest_point(&self, p: Vector3) -> Vector3 {
+        p.max(self.min).min(self.max)
+    }
+}
+
+impl Transform for Aabb {
+    /// Axis-aligned bounds of the transformed box.
+    fn transform(self, m: Matrix3x3) -> Self {
+        let e = self.half_extents();
+        let extents = Vector3::new(
+            m.row(0).abs().dot(e),
+            m.row(1).abs().dot(e),
+            m.row(2).abs().dot(e),
+        );
+        Aabb::from_center_half_extents(m * self.center(), extents)
+    }
+
+    fn translate(self, offset: Vector3) -> Self {
+        Aabb::new(self.min + offset, self.max + offset)
+    }
+}
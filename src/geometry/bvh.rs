@@ -0,0 +1,387 @@
+use super::raycast::slabs;
+use super::{Aabb, Overlap, PointQuery, Ray, RayHit, Sphere};
+use crate::prelude::{MathError, Vector3};
+
+/// Number of centroid bins evaluated per axis when choosing a split.
+const BINS: usize = 12;
+/// Leaves never hold more items than this unless their centroids coincide.
+const MAX_LEAF_SIZE: usize = 4;
+/// Cost of visiting an interior node relative to testing one item.
+const TRAVERSAL_COST: f32 = 1.;
+/// Nodes shallower than this choose splits with SAH. Nodes at this depth or
+/// deeper split at the median centroid, so skewed input cannot grow the tree
+/// linearly in the number of items.
+const MAX_SAH_DEPTH: usize = 32;
+
+#[derive(Debug, Clone, Copy)]
+enum NodeKind {
+    Leaf { start: usize, count: usize },
+    Interior { left: usize, right: usize },
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    bounds: Aabb,
+    kind: NodeKind,
+}
+
+/// Bounding volume hierarchy over items identified by their index into the
+/// slice of bounds it was built from. Queries return candidate indices or
+/// call back with them for exact tests.
+#[derive(Debug, Clone, Default)]
+pub struct Bvh {
+    /// Children are always stored after their parent.
+    nodes: Vec<Node>,
+    /// Item indices, grouped so that each leaf covers a contiguous range.
+    indices: Vec<usize>,
+    items: Vec<Aabb>,
+}
+
+fn merge_all(bounds: &[Aabb], indices: &[usize]) -> Aabb {
+    indices
+        .iter()
+        .map(|&i| bounds[i])
+        .reduce(Aabb::merge)
+        .expect("nodes hold at least one item")
+}
+
+fn merge_option(a: Option<Aabb>, b: Aabb) -> Option<Aabb> {
+    Some(a.map_or(b, |a| a.merge(b)))
+}
+
+/// Best binned SAH split of `indices`, as `(axis, bin)` with items in bins
+/// `0..=bin` going left, or `None` when keeping a leaf is cheaper.
+fn sah_split(
+    bounds: &[Aabb],
+    indices: &[usize],
+    node_bounds: Aabb,
+    centroids: Aabb,
+) -> Option<(usize, usize)> {
+    let parent_area = node_bounds.surface_area();
+    let mut best: Option<(f32, usize, usize)> = None;
+
+    for axis in 0..3 {
+        let extent = centroids.max[axis] - centroids.min[axis];
+        if extent <= 0. {
+            continue;
+        }
+        let mut counts = [0usize; BINS];
+        let mut bin_bounds = [None; BINS];
+        for &i in indices {
+            let bin = bin_index(bounds[i].center()[axis], centroids.min[axis], extent);
+            counts[bin] += 1;
+            bin_bounds[bin] = merge_option(bin_bounds[bin], bounds[i]);
+        }
+
+        // Sweep from the right to get the cost of every right-hand side.
+        let mut right_costs = [0.; BINS];
+        let mut right: Option<Aabb> = None;
+        let mut right_count = 0;
+        for bin in (1..BINS).rev() {
+            if let Some(b) = bin_bounds[bin] {
+                right = merge_option(right, b);
+            }
+            right_count += counts[bin];
+            right_costs[bin] = right.map_or(0., |r| r.surface_area() * right_count as f32);
+        }
+
+        let mut left: Option<Aabb> = None;
+        let mut left_count = 0;
+        for bin in 0..BINS - 1 {
+            if let Some(b) = bin_bounds[bin] {
+                left = merge_option(left, b);
+            }
+            left_count += counts[bin];
+            if left_count == 0 || left_count == indices.len() {
+                continue;
+            }
+            let left_cost = left.map_or(0., |l| l.surface_area() * left_count as f32);
+            let cost = TRAVERSAL_COST
+                + (left_cost + right_costs[bin + 1]) / parent_area.max(f32::MIN_POSITIVE);
+            if best.is_none_or(|(c, _, _)| cost < c) {
+                best = Some((cost, axis, bin));
+            }
+        }
+    }
+
+    let (cost, axis, bin) = best?;
+    let leaf_cost = indices.len() as f32;
+    (cost < leaf_cost || indices.len() > MAX_LEAF_SIZE).then_some((axis, bin))
+}
+
+fn bin_index(centroid: f32, min: f32, extent: f32) -> usize {
+    (((centroid - min) / extent * BINS as f32) as usize).min(BINS - 1)
+}
+
+impl Bvh {
+    /// Builds the hierarchy top-down, choosing each split with the surface
+    /// area heuristic over binned centroids.
+    pub fn build(bounds: &[Aabb]) -> Bvh {
+        let mut bvh = Bvh {
+            nodes: Vec::new(),
+            indices: (0..bounds.len()).collect(),
+            items: bounds.to_vec(),
+        };
+        if !bounds.is_empty() {
+            bvh.build_node(bounds, 0, bounds.len(), 0);
+        }
+        bvh
+    }
+
+    fn build_node(&mut self, bounds: &[Aabb], start: usize, end: usize, depth: usize) -> usize {
+        let indices = &mut self.indices[start..end];
+        let node_bounds = merge_all(bounds, indices);
+        let centroids = indices
+            .iter()
+            .map(|&i| {
+                let c = bounds[i].center();
+                Aabb::new(c, c)
+            })
+            .reduce(Aabb::merge)
+            .expect("nodes hold at least one item");
+
+        let node = self.nodes.len();
+        self.nodes.push(Node {
+            bounds: node_bounds,
+            kind: NodeKind::Leaf {
+                start,
+                count: end - start,
+            },
+        });
+
+        let Some((axis, bin)) = sah_split(bounds, indices, node_bounds, centroids) else {
+            return node;
+        };
+        let middle = start
+            + if depth < MAX_SAH_DEPTH {
+                let extent = centroids.max[axis] - centroids.min[axis];
+                indices.sort_by_key(|&i| {
+                    bin_index(bounds[i].center()[axis], centroids.min[axis], extent) > bin
+                });
+                indices
+                    .iter()
+                    .take_while(|&&i| {
+                        bin_index(bounds[i].center()[axis], centroids.min[axis], extent) <= bin
+                    })
+                    .count()
+            } else {
+                let axis = (0..3)
+                    .max_by(|&a, &b| {
+                        let extent = |i: usize| centroids.max[i] - centroids.min[i];
+                        extent(a).total_cmp(&extent(b))
+                    })
+                    .expect("three axes");
+                let half = indices.len() / 2;
+                indices.select_nth_unstable_by(half, |&a, &b| {
+                    bounds[a].center()[axis].total_cmp(&bounds[b].center()[axis])
+                });
+                half
+            };
+
+        let left = self.build_node(bounds, start, middle, depth + 1);
+        let right = self.build_node(bounds, middle, end, depth + 1);
+        self.nodes[node].kind = NodeKind::Interior { left, right };
+        node
+    }
+
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Bounds of every item, or `None` for an empty hierarchy.
+    pub fn bounds(&self) -> Option<Aabb> {
+        self.nodes.first().map(|node| node.bounds)
+    }
+
+    /// Updates node bounds after items moved, keeping the tree topology.
+    /// Fails when `bounds` does not hold as many items as the hierarchy was
+    /// built with.
+    pub fn refit(&mut self, bounds: &[Aabb]) -> Result<(), MathError> {
+        if bounds.len() != self.items.len() {
+            return Err(MathError::LengthMismatch);
+        }
+        self.items.copy_from_slice(bounds);
+        for node in (0..self.nodes.len()).rev() {
+            self.nodes[node].bounds = match self.nodes[node].kind {
+                NodeKind::Leaf { start, count } => {
+                    merge_all(bounds, &self.indices[start..start + count])
+                }
+                NodeKind::Interior { left, right } => {
+                    self.nodes[left].bounds.merge(self.nodes[right].bounds)
+                }
+            };
+        }
+        Ok(())
+    }
+
+    fn leaf_items(&self, start: usize, count: usize) -> &[usize] {
+        &self.indices[start..start + count]
+    }
+
+    /// Distance along `ray` to where it enters `bounds`, zero if it starts
+    /// inside.
+    fn entry(ray: Ray, bounds: &Aabb) -> Option<f32> {
+        slabs(ray, bounds).map(|((entry, _), _)| entry.max(0.))
+    }
+
+    /// Closest hit within `max_distance`. `hit` performs the exact test for an
+    /// item index.
+    pub fn cast_ray(
+        &self,
+        ray: Ray,
+        max_distance: f32,
+        mut hit: impl FnMut(usize) -> Option<RayHit>,
+    ) -> Option<(usize, RayHit)> {
+        let mut best: Option<(usize, RayHit)> = None;
+        let mut limit = max_distance;
+        let mut stack: Vec<(usize, f32)> = Vec::new();
+        if let Some(t) = self.nodes.first().and_then(|n| Bvh::entry(ray, &n.bounds)) {
+            stack.push((0, t));
+        }
+
+        while let Some((node, t)) = stack.pop() {
+            if t > limit {
+                continue;
+            }
+            match self.nodes[node].kind {
+                NodeKind::Leaf { start, count } => {
+                    for &item in self.leaf_items(start, count) {
+                        if let Some(h) = hit(item).filter(|h| h.distance <= limit) {
+                            limit = h.distance;
+                            best = Some((item, h));
+                        }
+                    }
+                }
+                NodeKind::Interior { left, right } => {
+                    let near = Bvh::entry(ray, &self.nodes[left].bounds).map(|t| (left, t));
+                    let far = Bvh::entry(ray, &self.nodes[right].bounds).map(|t| (right, t));
+                    // Push the farther child first so the nearer one is visited next.
+                    let (near, far) = match (near, far) {
+                        (Some(a), Some(b)) if b.1 < a.1 => (Some(b), Some(a)),
+                        pair => pair,
+                    };
+                    stack.extend(far);
+                    stack.extend(near);
+                }
+            }
+        }
+        best
+    }
+
+    /// Any hit within `max_distance`, stopping at the first one found. Suited
+    /// to occlusion and shadow rays.
+    pub fn cast_ray_any(
+        &self,
+        ray: Ray,
+        max_distance: f32,
+        mut hit: impl FnMut(usize) -> Option<RayHit>,
+    ) -> Option<(usize, RayHit)> {
+        let mut stack = Vec::new();
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(node) = stack.pop() {
+            let node = self.nodes[node];
+            if Bvh::entry(ray, &node.bounds).is_none_or(|t| t > max_distance) {
+                continue;
+            }
+            match node.kind {
+                NodeKind::Leaf { start, count } => {
+                    for &item in self.leaf_items(start, count) {
+                        if let Some(h) = hit(item).filter(|h| h.distance <= max_distance) {
+                            return Some((item, h));
+                        }
+                    }
+                }
+                NodeKind::Interior { left, right } => stack.extend([right, left]),
+            }
+        }
+        None
+    }
+
+    /// Items whose bounds satisfy `test`, given that `test` holds for a box
+    /// whenever it holds for a box inside it.
+    fn collect(&self, test: impl Fn(&Aabb) -> bool) -> Vec<usize> {
+        let mut found = Vec::new();
+        let mut stack = Vec::new();
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(node) = stack.pop() {
+            let node = self.nodes[node];
+            if !test(&node.bounds) {
+                continue;
+            }
+            match node.kind {
+                NodeKind::Leaf { start, count } => found.extend(
+                    self.leaf_items(start, count)
+                        .iter()
+                        .filter(|&&i| test(&self.items[i])),
+                ),
+                NodeKind::Interior { left, right } => stack.extend([right, left]),
+            }
+        }
+        found
+    }
+
+    /// Indices of items whose bounds overlap `aabb`.
+    pub fn query_aabb(&self, aabb: Aabb) -> Vec<usize> {
+        self.collect(|bounds| bounds.overlaps(&aabb))
+    }
+
+    /// Indices of items whose bounds overlap `sphere`.
+    pub fn query_sphere(&self, sphere: Sphere) -> Vec<usize> {
+        self.collect(|bounds| sphere.overlaps(bounds))
+    }
+
+    /// Item closest to `p`, with its closest point and distance. `closest`
+    /// returns the point of an item nearest to `p`.
+    pub fn nearest(
+        &self,
+        p: Vector3,
+        mut closest: impl FnMut(usize) -> Vector3,
+    ) -> Option<(usize, Vector3, f32)> {
+        let mut best: Option<(usize, Vector3, f32)> = None;
+        let mut limit = f32::INFINITY;
+        let mut stack: Vec<(usize, f32)> = Vec::new();
+        if let Some(root) = self.nodes.first() {
+            stack.push((0, root.bounds.distance_to_point(p)));
+        }
+
+        while let Some((node, distance)) = stack.pop() {
+            if distance > limit {
+                continue;
+            }
+            match self.nodes[node].kind {
+                NodeKind::Leaf { start, count } => {
+                    for &item in self.leaf_items(start, count) {
+                        if self.items[item].distance_to_point(p) > limit {
+                            continue;
+                        }
+                        let point = closest(item);
+                        let d = (point - p).magnitude();
+                        if d < limit {
+                            limit = d;
+                            best = Some((item, point, d));
+                        }
+                    }
+                }
+                NodeKind::Interior { left, right } => {
+                    let near = (left, self.nodes[left].bounds.distance_to_point(p));
+                    let far = (right, self.nodes[right].bounds.distance_to_point(p));
+                    let (near, far) = if far.1 < near.1 {
+                        (far, near)
+                    } else {
+                        (near, far)
+                    };
+                    stack.extend([far, near]);
+                }
+            }
+        }
+        best
+    }
+}
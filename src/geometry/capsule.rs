@@ -0,0 +1,51 @@
+use super::{max_scale, LineSegment, PointQuery, Transform};
+use crate::prelude::{Matrix3x3, Quaternion, Vector3};
+
+/// Points within `radius` of the segment from `start` to `end`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Capsule {
+    pub start: Vector3,
+    pub end: Vector3,
+    pub radius: f32,
+}
+
+impl Capsule {
+    pub fn new(start: Vector3, end: Vector3, radius: f32) -> Self {
+        Capsule { start, end, radius }
+    }
+
+    pub fn segment(self) -> LineSegment {
+        LineSegment::new(self.start, self.end)
+    }
+}
+
+impl PointQuery for Capsule {
+    fn contains_point(&self, p: Vector3) -> bool {
+        (self.segment().closest_point(p) - p).magnitude_squared() <= self.radius * self.radius
+    }
+
+    fn closest_point(&self, p: Vector3) -> Vector3 {
+        let axis_point = self.segment().closest_point(p);
+        let offset = p - axis_point;
+        let distance = offset.magnitude();
+        if distance <= self.radius {
+            return p;
+        }
+        axis_point + offset * (self.radius / distance)
+    }
+}
+
+impl Transform for Capsule {
+    /// The result bounds the transformed capsule when `m` is not a similarity.
+    fn transform(self, m: Matrix3x3) -> Self {
+        Capsule::new(m * self.start, m * self.end, self.radius * max_scale(m))
+    }
+
+    fn translate(self, offset: Vector3) -> Self {
+        Capsule::new(self.start + offset, self.end + offset, self.radius)
+    }
+
+    fn rotate(self, q: Quaternion) -> Self {
+        Capsule::new(q.rotate(self.start), q.rotate(self.end), self.radius)
+    }
+}
@@ -0,0 +1,201 @@
+use super::{LineSegment, Obb, PointQuery, Ray, Triangle};
+use crate::prelude::Vector3;
+
+/// Closest points between two shapes, `point_a` on the first and `point_b`
+/// on the second. For intersecting shapes both are a common point.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ClosestPoints {
+    pub point_a: Vector3,
+    pub point_b: Vector3,
+    pub distance_squared: f32,
+}
+
+impl ClosestPoints {
+    fn new(point_a: Vector3, point_b: Vector3) -> Self {
+        ClosestPoints {
+            point_a,
+            point_b,
+            distance_squared: (point_b - point_a).magnitude_squared(),
+        }
+    }
+
+    /// The same points seen from the other shape.
+    pub fn flip(self) -> ClosestPoints {
+        ClosestPoints {
+            point_a: self.point_b,
+            point_b: self.point_a,
+            distance_squared: self.distance_squared,
+        }
+    }
+}
+
+/// Closest-point query against shapes of type `T`.
+pub trait Proximity<T> {
+    fn closest_points(&self, other: &T) -> ClosestPoints;
+}
+
+fn nearest(candidates: impl IntoIterator<Item = ClosestPoints>) -> ClosestPoints {
+    candidates
+        .into_iter()
+        .min_by(|a, b| a.distance_squared.total_cmp(&b.distance_squared))
+        .expect("at least one candidate")
+}
+
+/// Parameters `(s, t)` of the closest points of `p1 + s * d1` with `s` in
+/// `[0, max_s]` and `p2 + t * d2` with `t` in `[0, 1]`. Ericson, Real-Time
+/// Collision Detection, 5.1.9, with the first range left open for rays.
+fn closest_parameters(
+    p1: Vector3,
+    d1: Vector3,
+    max_s: f32,
+    p2: Vector3,
+    d2: Vector3,
+) -> (f32, f32) {
+    let r = p1 - p2;
+    let a = d1.magnitude_squared();
+    let e = d2.magnitude_squared();
+    let f = d2.dot(r);
+
+    if a <= f32::EPSILON && e <= f32::EPSILON {
+        return (0., 0.);
+    }
+    if a <= f32::EPSILON {
+        return (0., (f / e).clamp(0., 1.));
+    }
+    let c = d1.dot(r);
+    if e <= f32::EPSILON {
+        return ((-c / a).clamp(0., max_s), 0.);
+    }
+
+    let b = d1.dot(d2);
+    let denom = a * e - b * b;
+    // Parallel lines have no unique closest pair; start from `s = 0`.
+    let mut s = if denom > f32::EPSILON * a * e {
+        ((b * f - c * e) / denom).clamp(0., max_s)
+    } else {
+        0.
+    };
+    let mut t = (b * s + f) / e;
+    if t < 0. {
+        t = 0.;
+        s = (-c / a).clamp(0., max_s);
+    } else if t > 1. {
+        t = 1.;
+        s = ((b - c) / a).clamp(0., max_s);
+    }
+    (s, t)
+}
+
+/// Where the segment pierces the triangle, if it does.
+fn segment_triangle_intersection(segment: &LineSegment, triangle: &Triangle) -> Option<Vector3> {
+    let length = segment.length();
+    if length == 0. {
+        return None;
+    }
+    let ray = Ray::new(segment.start, segment.end - segment.start);
+    ray.intersect_triangle(*triangle)
+        .filter(|hit| hit.hit.distance <= length)
+        .map(|hit| hit.hit.point)
+}
+
+fn edges(triangle: &Triangle) -> [LineSegment; 3] {
+    let Triangle { a, b, c } = *triangle;
+    [
+        LineSegment::new(a, b),
+        LineSegment::new(b, c),
+        LineSegment::new(c, a),
+    ]
+}
+
+impl Proximity<LineSegment> for LineSegment {
+    fn closest_points(&self, other: &LineSegment) -> ClosestPoints {
+        let (s, t) = closest_parameters(
+            self.start,
+            self.end - self.start,
+            1.,
+            other.start,
+            other.end - other.start,
+        );
+        ClosestPoints::new(self.at(s), other.at(t))
+    }
+}
+
+impl Proximity<LineSegment> for Ray {
+    fn closest_points(&self, other: &LineSegment) -> ClosestPoints {
+        let (s, t) = closest_parameters(
+            self.origin,
+            self.direction,
+            f32::INFINITY,
+            other.start,
+            other.end - other.start,
+        );
+        ClosestPoints::new(self.at(s), other.at(t))
+    }
+}
+
+impl Proximity<Ray> for LineSegment {
+    fn closest_points(&self, other: &Ray) -> ClosestPoints {
+        other.closest_points(self).flip()
+    }
+}
+
+impl Proximity<Triangle> for Vector3 {
+    fn closest_points(&self, other: &Triangle) -> ClosestPoints {
+        ClosestPoints::new(*self, other.closest_point(*self))
+    }
+}
+
+impl Proximity<Obb> for Vector3 {
+    fn closest_points(&self, other: &Obb) -> ClosestPoints {
+        ClosestPoints::new(*self, other.closest_point(*self))
+    }
+}
+
+impl Proximity<Triangle> for LineSegment {
+    fn closest_points(&self, other: &Triangle) -> ClosestPoints {
+        if let Some(p) = segment_triangle_intersection(self, other) {
+            return ClosestPoints::new(p, p);
+        }
+        // Otherwise the closest pair involves a segment endpoint or a
+        // triangle edge.
+        nearest(
+            edges(other)
+                .map(|edge| self.closest_points(&edge))
+                .into_iter()
+                .chain([self.start, self.end].map(|p| p.closest_points(other))),
+        )
+    }
+}
+
+impl Proximity<LineSegment> for Triangle {
+    fn closest_points(&self, other: &LineSegment) -> ClosestPoints {
+        other.closest_points(self).flip()
+    }
+}
+
+impl Proximity<Triangle> for Triangle {
+    fn closest_points(&self, other: &Triangle) -> ClosestPoints {
+        // A non-coplanar intersection has an edge of one triangle piercing
+        // the other.
+        let piercing = edges(self)
+            .iter()
+            .find_map(|edge| segment_triangle_intersection(edge, other))
+            .or_else(|| {
+                edges(other)
+                    .iter()
+                    .find_map(|edge| segment_triangle_intersection(edge, self))
+            });
+        if let Some(p) = piercing {
+            return ClosestPoints::new(p, p);
+        }
+
+        let own = edges(self);
+        let theirs = edges(other);
+        let edge_pairs = own
+            .iter()
+            .flat_map(|a| theirs.iter().map(move |b| a.closest_points(b)));
+        let vertices_a = [self.a, self.b, self.c].map(|p| p.closest_points(other));
+        let vertices_b = [other.a, other.b, other.c].map(|p| p.closest_points(self).flip());
+        nearest(edge_pairs.chain(vertices_a).chain(vertices_b))
+    }
+}
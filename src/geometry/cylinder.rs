@@ -0,0 +1,65 @@
+use super::{max_scale, LineSegment, PointQuery, Transform};
+use crate::prelude::{Matrix3x3, Quaternion, Vector3};
+
+/// Capped cylinder of `radius` around the segment from `start` to `end`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Cylinder {
+    pub start: Vector3,
+    pub end: Vector3,
+    pub radius: f32,
+}
+
+impl Cylinder {
+    pub fn new(start: Vector3, end: Vector3, radius: f32) -> Self {
+        Cylinder { start, end, radius }
+    }
+
+    pub fn axis(self) -> LineSegment {
+        LineSegment::new(self.start, self.end)
+    }
+}
+
+impl PointQuery for Cylinder {
+    fn contains_point(&self, p: Vector3) -> bool {
+        let axis = self.end - self.start;
+        let length_squared = axis.magnitude_squared();
+        let s = (p - self.start).dot(axis);
+        if s < 0. || s > length_squared {
+            return false;
+        }
+        (p - self.start).reject_from(axis).magnitude_squared() <= self.radius * self.radius
+    }
+
+    fn closest_point(&self, p: Vector3) -> Vector3 {
+        let axis = self.end - self.start;
+        let length_squared = axis.magnitude_squared();
+        let s = if length_squared == 0. {
+            0.
+        } else {
+            ((p - self.start).dot(axis) / length_squared).clamp(0., 1.)
+        };
+        let radial = (p - self.start).reject_from(axis);
+        let distance = radial.magnitude();
+        let radial = if distance > self.radius {
+            radial * (self.radius / distance)
+        } else {
+            radial
+        };
+        self.start + axis * s + radial
+    }
+}
+
+impl Transform for Cylinder {
+    /// The result bounds the transformed cylinder when `m` is not a similarity.
+    fn transform(self, m: Matrix3x3) -> Self {
+        Cylinder::new(m * self.start, m * self.end, self.radius * max_scale(m))
+    }
+
+    fn translate(self, offset: Vector3) -> Self {
+        Cylinder::new(self.start + offset, self.end + offset, self.radius)
+    }
+
+    fn rotate(self, q: Quaternion) -> Self {
+        Cylinder::new(q.rotate(self.start), q.rotate(self.end), self.radius)
+    }
+}
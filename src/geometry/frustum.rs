@@ -0,0 +1,208 @@
+use super::{Aabb, Interval, Plane, Side, Sphere};
+use crate::prelude::{MathError, Matrix3x3, Quaternion, Radians, Vector3};
+
+/// Clip-space depth convention of a projection matrix.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DepthRange {
+    /// OpenGL style, `-w <= z <= w`.
+    NegativeOneToOne,
+    /// Direct3D, Vulkan and Metal style, `0 <= z <= w`.
+    ZeroToOne,
+}
+
+/// Position of a shape relative to a [`Frustum`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Containment {
+    Inside,
+    Intersecting,
+    Outside,
+}
+
+/// Convex volume bounded by six planes whose normals point inwards, ordered
+/// left, right, bottom, top, near, far.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Frustum {
+    pub planes: [Plane; 6],
+}
+
+const LEFT: usize = 0;
+const RIGHT: usize = 1;
+const BOTTOM: usize = 2;
+const TOP: usize = 3;
+const NEAR: usize = 4;
+const FAR: usize = 5;
+
+impl Frustum {
+    /// Gribb–Hartmann plane extraction from a view-projection matrix that
+    /// maps world points `[x, y, z, 1]` to clip space. The matrix is indexed
+    /// `m[row][col]`, so the translation is `m[0][3]`, `m[1][3]`, `m[2][3]`.
+    /// This is the transpose of the column-major layout of
+    /// [`Matrix3x3::data`]; column-major arrays, such as those uploaded to
+    /// OpenGL, must be transposed first. Fails when the matrix yields a plane
+    /// with a zero normal.
+    pub fn from_view_projection(m: [[f32; 4]; 4], depth: DepthRange) -> Result<Frustum, MathError> {
+        // `p[0..3] · x + p[3] >= 0` inside.
+        let plane = |p: [f32; 4]| Plane::new(Vector3::new(p[0], p[1], p[2]), -p[3]);
+        let add = |a: [f32; 4], b: [f32; 4]| plane([0, 1, 2, 3].map(|i| a[i] + b[i]));
+        let sub = |a: [f32; 4], b: [f32; 4]| plane([0, 1, 2, 3].map(|i| a[i] - b[i]));
+        let near = match depth {
+            DepthRange::NegativeOneToOne => add(m[3], m[2]),
+            DepthRange::ZeroToOne => plane(m[2]),
+        };
+        Ok(Frustum {
+            planes: [
+                add(m[3], m[0])?,
+                sub(m[3], m[0])?,
+                add(m[3], m[1])?,
+                sub(m[3], m[1])?,
+                near?,
+                sub(m[3], m[2])?,
+            ],
+        })
+    }
+
+    /// Perspective frustum of a camera at `position` looking along its local
+    /// +Z with +Y up, matching [`Quaternion::look_rotation`]. `fov_y` is the
+    /// full vertical field of view and `aspect` is width over height. Fails
+    /// when the frustum has no volume, such as for a zero `near` or `fov_y`.
+    pub fn perspective(
+        position: Vector3,
+        rotation: Quaternion,
+        fov_y: impl Into<Radians>,
+        aspect: f32,
+        near: f32,
+        far: f32,
+    ) -> Result<Frustum, MathError> {
+        let tan = (fov_y.into().0 * 0.5).tan();
+        let corner = |i: usize| {
+            let depth = if i & 4 == 0 { near } else { far };
+            let x = if i & 1 == 0 { -1. } else { 1. };
+            let y = if i & 2 == 0 { -1. } else { 1. };
+            let local = Vector3::new(x * tan * aspect * depth, y * tan * depth, depth);
+            rotation.rotate(local) + position
+        };
+        Frustum::from_corners([0, 1, 2, 3, 4, 5, 6, 7].map(corner))
+    }
+
+    /// Frustum through corners in the order returned by
+    /// [`Frustum::corners`]. Fails when three corners spanning a face are
+    /// collinear.
+    pub fn from_corners(corners: [Vector3; 8]) -> Result<Frustum, MathError> {
+        let center = corners
+            .iter()
+            .fold(Vector3::default(), |sum, c| sum + *c * 0.125);
+        // Each plane through three corners of one face, facing the center.
+        let face = |a: usize, b: usize, c: usize| {
+            let plane = Plane::from_points(corners[a], corners[b], corners[c])?;
+            Ok(if plane.signed_distance(center) < 0. {
+                plane.flip()
+            } else {
+                plane
+            })
+        };
+        Ok(Frustum {
+            planes: [
+                face(0, 2, 4)?,
+                face(1, 5, 3)?,
+                face(0, 4, 1)?,
+                face(2, 3, 6)?,
+                face(0, 1, 2)?,
+                face(4, 6, 5)?,
+            ],
+        })
+    }
+
+    /// Corners indexed by bits `x | y << 1 | z << 2`, where a set bit selects
+    /// the right, top and far plane respectively. Same ordering as
+    /// [`Aabb::corners`]. Fails when three planes meeting at a corner are
+    /// parallel or nearly so.
+    pub fn corners(self) -> Result<[Vector3; 8], MathError> {
+        let mut corners = [Vector3::default(); 8];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            let x = self.planes[if i & 1 == 0 { LEFT } else { RIGHT }];
+            let y = self.planes[if i & 2 == 0 { BOTTOM } else { TOP }];
+            let z = self.planes[if i & 4 == 0 { NEAR } else { FAR }];
+            let normals = Matrix3x3::from_rows(x.normal, y.normal, z.normal);
+            let distances = Vector3::new(x.distance, y.distance, z.distance);
+            *corner = normals
+                .lu()
+                .solve(distances)
+                .map_err(|_| MathError::Degenerate)?;
+        }
+        Ok(corners)
+    }
+
+    pub fn contains_point(self, p: Vector3) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(p) >= 0.)
+    }
+
+    /// Tests `shape` against each plane in turn. Shapes near the frustum's
+    /// edges can be reported as intersecting while lying just outside, which
+    /// is the usual trade-off for culling.
+    pub fn classify<T: Interval>(self, shape: &T) -> Containment {
+        let mut containment = Containment::Inside;
+        for plane in self.planes {
+            match plane.classify(shape) {
+                Side::Back => return Containment::Outside,
+                Side::Intersecting => containment = Containment::Intersecting,
+                Side::Front => {}
+            }
+        }
+        containment
+    }
+
+    pub fn intersects_sphere(self, sphere: &Sphere) -> bool {
+        self.classify(sphere) != Containment::Outside
+    }
+
+    pub fn intersects_aabb(self, aabb: &Aabb) -> Containment {
+        self.classify(aabb)
+    }
+
+    /// Splits the frustum along its depth into one sub-frustum per
+    /// consecutive pair of `splits`, which are fractions of the near-to-far
+    /// range such as those from [`Frustum::cascade_splits`]. Fails when the
+    /// frustum or one of the slices is degenerate.
+    pub fn cascades(self, splits: &[f32]) -> Result<Vec<Frustum>, MathError> {
+        let corners = self.corners()?;
+        let slice = |t: f32| [0, 1, 2, 3].map(|i| corners[i].lerp(corners[i | 4], t));
+        splits
+            .windows(2)
+            .map(|pair| {
+                let (near, far) = (slice(pair[0]), slice(pair[1]));
+                Frustum::from_corners([
+                    near[0], near[1], near[2], near[3], far[0], far[1], far[2], far[3],
+                ])
+            })
+            .collect()
+    }
+
+    /// Practical split scheme for `count` shadow cascades between view depths
+    /// `near` and `far`, blending logarithmic and uniform splits by `lambda`
+    /// in `[0, 1]`. Returns `count + 1` fractions from 0 to 1. Fails with
+    /// [`MathError::OutOfRange`] unless `count >= 1`, `0 < near < far` and
+    /// `lambda` lies in `[0, 1]`.
+    pub fn cascade_splits(
+        near: f32,
+        far: f32,
+        count: usize,
+        lambda: f32,
+    ) -> Result<Vec<f32>, MathError> {
+        let valid = near > 0. && far > near && far.is_finite() && (0. ..=1.).contains(&lambda);
+        if count == 0 || !valid {
+            return Err(MathError::OutOfRange);
+        }
+        let splits = (0..=count)
+            .map(|i| {
+                let f = i as f32 / count as f32;
+                let logarithmic = near * (far / near).powf(f);
+                let uniform = near + (far - near) * f;
+                let depth = lambda * logarithmic + (1. - lambda) * uniform;
+                (depth - near) / (far - near)
+            })
+            .collect();
+        Ok(splits)
+    }
+}
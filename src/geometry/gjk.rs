@@ -0,0 +1,395 @@
+use super::{Contact, SupportMap};
+use crate::prelude::Vector3;
+
+const MAX_ITERATIONS: usize = 64;
+/// GJK stops once the lower bound on the distance is within this fraction of
+/// the upper bound.
+const RELATIVE_TOLERANCE: f32 = 1e-5;
+/// EPA stops once a face is within this distance of the true boundary.
+const EPA_TOLERANCE: f32 = 1e-5;
+
+/// Closest points of two disjoint convex shapes.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Separation {
+    pub distance: f32,
+    pub point_a: Vector3,
+    pub point_b: Vector3,
+}
+
+/// A point of the Minkowski difference `a - b` with the points it came from.
+#[derive(Debug, Clone, Copy)]
+struct Vertex {
+    w: Vector3,
+    a: Vector3,
+    b: Vector3,
+}
+
+fn support<A, B>(a: &A, b: &B, direction: Vector3) -> Vertex
+where
+    A: SupportMap + ?Sized,
+    B: SupportMap + ?Sized,
+{
+    let a = a.support(direction);
+    let b = b.support(-direction);
+    Vertex { w: a - b, a, b }
+}
+
+/// Sub-simplex closest to the origin with the barycentric weight of each
+/// remaining vertex.
+type Reduced = Vec<(Vertex, f32)>;
+
+fn closest_on_segment(a: Vertex, b: Vertex) -> Reduced {
+    let ab = b.w - a.w;
+    let length_squared = ab.magnitude_squared();
+    if length_squared == 0. {
+        return vec![(a, 1.)];
+    }
+    let t = -a.w.dot(ab) / length_squared;
+    if t <= 0. {
+        vec![(a, 1.)]
+    } else if t >= 1. {
+        vec![(b, 1.)]
+    } else {
+        vec![(a, 1. - t), (b, t)]
+    }
+}
+
+/// Ericson, Real-Time Collision Detection, 5.1.5, for the origin.
+fn closest_on_triangle(a: Vertex, b: Vertex, c: Vertex) -> Reduced {
+    let ab = b.w - a.w;
+    let ac = c.w - a.w;
+
+    let ap = -a.w;
+    let d1 = ab.dot(ap);
+    let d2 = ac.dot(ap);
+    if d1 <= 0. && d2 <= 0. {
+        return vec![(a, 1.)];
+    }
+
+    let bp = -b.w;
+    let d3 = ab.dot(bp);
+    let d4 = ac.dot(bp);
+    if d3 >= 0. && d4 <= d3 {
+        return vec![(b, 1.)];
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0. && d1 >= 0. && d3 <= 0. {
+        let t = d1 / (d1 - d3);
+        return vec![(a, 1. - t), (b, t)];
+    }
+
+    let cp = -c.w;
+    let d5 = ab.dot(cp);
+    let d6 = ac.dot(cp);
+    if d6 >= 0. && d5 <= d6 {
+        return vec![(c, 1.)];
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0. && d2 >= 0. && d6 <= 0. {
+        let t = d2 / (d2 - d6);
+        return vec![(a, 1. - t), (c, t)];
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0. && (d4 - d3) >= 0. && (d5 - d6) >= 0. {
+        let t = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return vec![(b, 1. - t), (c, t)];
+    }
+
+    let sum = va + vb + vc;
+    if sum == 0. {
+        // Collinear vertices: fall back to the longest edge.
+        return [(a, b), (a, c), (b, c)]
+            .map(|(p, q)| closest_on_segment(p, q))
+            .into_iter()
+            .min_by(|x, y| {
+                point(x, |v| v.w)
+                    .magnitude_squared()
+                    .total_cmp(&point(y, |v| v.w).magnitude_squared())
+            })
+            .unwrap_or_default();
+    }
+    vec![(a, va / sum), (b, vb / sum), (c, vc / sum)]
+}
+
+/// Returns the whole tetrahedron when it contains the origin.
+fn closest_on_tetrahedron(vertices: [Vertex; 4]) -> Reduced {
+    let [a, b, c, d] = vertices;
+    let faces = [(a, b, c, d), (a, c, d, b), (a, d, b, c), (b, d, c, a)];
+    let mut best: Option<(f32, Reduced)> = None;
+    for (p, q, r, opposite) in faces {
+        let normal = (q.w - p.w).cross(r.w - p.w);
+        let origin_side = -p.w.dot(normal);
+        let opposite_side = (opposite.w - p.w).dot(normal);
+        // Only faces that separate the origin from the opposite vertex can
+        // hold the closest point. A flat tetrahedron checks every face.
+        if origin_side * opposite_side > 0. {
+            continue;
+        }
+        let reduced = closest_on_triangle(p, q, r);
+        let distance = point(&reduced, |v| v.w).magnitude_squared();
+        if best.as_ref().is_none_or(|(d, _)| distance < *d) {
+            best = Some((distance, reduced));
+        }
+    }
+    match best {
+        Some((_, reduced)) => reduced,
+        None => vertices.map(|v| (v, 0.25)).to_vec(),
+    }
+}
+
+fn point(reduced: &Reduced, f: impl Fn(&Vertex) -> Vector3) -> Vector3 {
+    reduced
+        .iter()
+        .fold(Vector3::default(), |sum, (v, weight)| sum + f(v) * *weight)
+}
+
+fn closest_on_simplex(simplex: &[Vertex]) -> Reduced {
+    match *simplex {
+        [a] => vec![(a, 1.)],
+        [a, b] => closest_on_segment(a, b),
+        [a, b, c] => closest_on_triangle(a, b, c),
+        [a, b, c, d] => closest_on_tetrahedron([a, b, c, d]),
+        _ => unreachable!("GJK simplex has 1 to 4 vertices"),
+    }
+}
+
+enum Gjk {
+    Separated(Separation),
+    /// The final simplex, which contains the origin.
+    Intersecting(Vec<Vertex>),
+}
+
+fn gjk<A, B>(a: &A, b: &B) -> Gjk
+where
+    A: SupportMap + ?Sized,
+    B: SupportMap + ?Sized,
+{
+    let start = support(a, b, Vector3::new(1., 0., 0.));
+    let mut simplex = vec![start];
+    let mut v = start.w;
+    let mut reduced: Reduced = vec![(start, 1.)];
+
+    for _ in 0..MAX_ITERATIONS {
+        let v_squared = v.magnitude_squared();
+        if v_squared <= f32::EPSILON * f32::EPSILON {
+            return Gjk::Intersecting(simplex);
+        }
+        let w = support(a, b, -v);
+        let duplicate = simplex.iter().any(|s| s.w == w.w);
+        if duplicate || v_squared - v.dot(w.w) <= RELATIVE_TOLERANCE * v_squared {
+            break;
+        }
+
+        simplex.push(w);
+        reduced = closest_on_simplex(&simplex);
+        simplex = reduced.iter().map(|(vertex, _)| *vertex).collect();
+        if simplex.len() == 4 {
+            return Gjk::Intersecting(simplex);
+        }
+
+        let next = point(&reduced, |s| s.w);
+        // No progress: the simplex is as close as float precision allows.
+        if next.magnitude_squared() >= v_squared {
+            break;
+        }
+        v = next;
+    }
+
+    Gjk::Separated(Separation {
+        distance: v.magnitude(),
+        point_a: point(&reduced, |s| s.a),
+        point_b: point(&reduced, |s| s.b),
+    })
+}
+
+/// Grows an intersecting GJK simplex into a tetrahedron of non-zero volume.
+/// Fails with a unit direction along which the Minkowski difference is flat.
+fn blow_up<A, B>(a: &A, b: &B, mut simplex: Vec<Vertex>) -> Result<[Vertex; 4], Vector3>
+where
+    A: SupportMap + ?Sized,
+    B: SupportMap + ?Sized,
+{
+    let axes = [
+        Vector3::new(1., 0., 0.),
+        Vector3::new(0., 1., 0.),
+        Vector3::new(0., 0., 1.),
+    ];
+    let candidates = |simplex: &[Vertex]| -> Vec<Vector3> {
+        match simplex.len() {
+            1 => axes.into_iter().flat_map(|d| [d, -d]).collect(),
+            2 => {
+                let edge = simplex[1].w - simplex[0].w;
+                let (t, b) = edge.orthonormal_basis();
+                vec![t, -t, b, -b]
+            }
+            _ => {
+                let normal = (simplex[1].w - simplex[0].w).cross(simplex[2].w - simplex[0].w);
+                vec![normal, -normal]
+            }
+        }
+    };
+    let spans_new_dimension = |simplex: &[Vertex], w: Vector3| match simplex.len() {
+        1 => (w - simplex[0].w).magnitude_squared() > f32::EPSILON,
+        2 => {
+            let edge = simplex[1].w - simplex[0].w;
+            edge.cross(w - simplex[0].w).magnitude_squared() > f32::EPSILON
+        }
+        _ => {
+            let normal = (simplex[1].w - simplex[0].w).cross(simplex[2].w - simplex[0].w);
+            normal.dot(w - simplex[0].w).abs() > f32::EPSILON
+        }
+    };
+
+    while simplex.len() < 4 {
+        let directions = candidates(&simplex);
+        let next = directions
+            .iter()
+            .map(|d| support(a, b, *d))
+            .find(|v| spans_new_dimension(&simplex, v.w));
+        match next {
+            Some(next) => simplex.push(next),
+            None => return Err(directions[0].normalize()),
+        }
+    }
+    Ok([simplex[0], simplex[1], simplex[2], simplex[3]])
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    vertices: [usize; 3],
+    normal: Vector3,
+    distance: f32,
+}
+
+fn face(points: &[Vertex], vertices: [usize; 3], inside: Vector3) -> Option<Face> {
+    let [p, q, r] = vertices.map(|i| points[i].w);
+    let normal = (q - p).cross(r - p);
+    let length = normal.magnitude();
+    if length == 0. {
+        return None;
+    }
+    let mut normal = normal / length;
+    let mut vertices = vertices;
+    if normal.dot(p - inside) < 0. {
+        normal = -normal;
+        vertices.swap(1, 2);
+    }
+    Some(Face {
+        vertices,
+        normal,
+        distance: normal.dot(p),
+    })
+}
+
+/// Expanding polytope algorithm on a tetrahedron that contains the origin.
+fn epa<A, B>(a: &A, b: &B, tetrahedron: [Vertex; 4]) -> Contact
+where
+    A: SupportMap + ?Sized,
+    B: SupportMap + ?Sized,
+{
+    let mut points = tetrahedron.to_vec();
+    let inside = tetrahedron
+        .iter()
+        .fold(Vector3::default(), |sum, v| sum + v.w * 0.25);
+    let mut faces: Vec<Face> = [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]]
+        .into_iter()
+        .filter_map(|f| face(&points, f, inside))
+        .collect();
+
+    let mut best: Option<Face> = None;
+    for _ in 0..MAX_ITERATIONS {
+        let Some(&closest) = faces
+            .iter()
+            .min_by(|x, y| x.distance.total_cmp(&y.distance))
+        else {
+            break;
+        };
+        best = Some(closest);
+
+        let w = support(a, b, closest.normal);
+        if w.w.dot(closest.normal) - closest.distance <= EPA_TOLERANCE {
+            break;
+        }
+
+        // Remove every face that `w` can see and stitch the horizon to `w`.
+        let mut horizon: Vec<[usize; 2]> = Vec::new();
+        faces.retain(|f| {
+            let visible = f.normal.dot(w.w - points[f.vertices[0]].w) > 0.;
+            if visible {
+                for i in 0..3 {
+                    let edge = [f.vertices[i], f.vertices[(i + 1) % 3]];
+                    match horizon.iter().position(|e| *e == [edge[1], edge[0]]) {
+                        Some(shared) => {
+                            horizon.swap_remove(shared);
+                        }
+                        None => horizon.push(edge),
+                    }
+                }
+            }
+            !visible
+        });
+
+        points.push(w);
+        let new = points.len() - 1;
+        faces.extend(
+            horizon
+                .into_iter()
+                .filter_map(|[p, q]| face(&points, [p, q, new], inside)),
+        );
+    }
+
+    match best {
+        Some(best) => Contact {
+            normal: best.normal,
+            depth: best.distance.max(0.),
+        },
+        None => Contact {
+            normal: flat_direction(&tetrahedron),
+            depth: 0.,
+        },
+    }
+}
+
+/// Unit direction across a tetrahedron with no valid face, which happens
+/// when its vertices are collinear or coincide.
+fn flat_direction(tetrahedron: &[Vertex; 4]) -> Vector3 {
+    let edge = tetrahedron
+        .iter()
+        .map(|v| v.w - tetrahedron[0].w)
+        .max_by(|x, y| x.magnitude_squared().total_cmp(&y.magnitude_squared()))
+        .unwrap_or_default();
+    let normal = edge.any_orthogonal();
+    if normal == Vector3::default() {
+        Vector3::new(1., 0., 0.)
+    } else {
+        normal
+    }
+}
+
+pub(crate) fn separation<A, B>(a: &A, b: &B) -> Option<Separation>
+where
+    A: SupportMap + ?Sized,
+    B: SupportMap + ?Sized,
+{
+    match gjk(a, b) {
+        Gjk::Separated(separation) => Some(separation),
+        Gjk::Intersecting(_) => None,
+    }
+}
+
+pub(crate) fn penetration<A, B>(a: &A, b: &B) -> Option<Contact>
+where
+    A: SupportMap + ?Sized,
+    B: SupportMap + ?Sized,
+{
+    let Gjk::Intersecting(simplex) = gjk(a, b) else {
+        return None;
+    };
+    match blow_up(a, b, simplex) {
+        Ok(tetrahedron) => Some(epa(a, b, tetrahedron)),
+        Err(normal) => Some(Contact { normal, depth: 0. }),
+    }
+}
@@ -0,0 +1,245 @@
+use super::{SupportMap, EPSILON};
+use crate::prelude::{MathError, Vector3};
+
+/// Closed triangle mesh bounding a convex volume. Triangles wind
+/// counter-clockwise seen from outside, matching their outward `normals`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ConvexHull {
+    pub vertices: Vec<Vector3>,
+    pub triangles: Vec<[usize; 3]>,
+    pub normals: Vec<Vector3>,
+}
+
+struct Face {
+    vertices: [usize; 3],
+    normal: Vector3,
+    /// A vertex of the face. Measuring from it rather than from the origin
+    /// keeps distances accurate for clouds far from the origin.
+    anchor: Vector3,
+    /// Input points in front of this face and not yet on the hull.
+    outside: Vec<usize>,
+    alive: bool,
+}
+
+impl Face {
+    fn new(points: &[Vector3], vertices: [usize; 3]) -> Face {
+        let [a, b, c] = vertices.map(|i| points[i]);
+        let normal = (b - a).cross(c - a).normalize();
+        Face {
+            vertices,
+            normal,
+            anchor: a,
+            outside: Vec::new(),
+            alive: true,
+        }
+    }
+
+    fn distance(&self, p: Vector3) -> f32 {
+        self.normal.dot(p - self.anchor)
+    }
+}
+
+/// The candidate with the largest `key`, with that key. Candidates within
+/// `tolerance` of the largest key are ranked by their distance from `from`
+/// instead, which picks a hull vertex out of points along a common edge or
+/// face.
+fn farthest(
+    candidates: impl Iterator<Item = usize> + Clone,
+    points: &[Vector3],
+    key: impl Fn(usize) -> f32,
+    from: Vector3,
+    tolerance: f32,
+) -> Option<(usize, f32)> {
+    let max = candidates.clone().map(&key).reduce(f32::max)?;
+    candidates
+        .filter(|&i| key(i) >= max - tolerance)
+        .max_by(|&a, &b| {
+            let da = (points[a] - from).magnitude_squared();
+            let db = (points[b] - from).magnitude_squared();
+            da.total_cmp(&db)
+        })
+        .map(|i| (i, key(i)))
+}
+
+impl ConvexHull {
+    /// Quickhull. Points within a small tolerance of a face, including
+    /// duplicates and points coplanar with a face, are left off the hull.
+    /// Fails when the points do not span a volume.
+    pub fn from_points(points: &[Vector3]) -> Result<ConvexHull, MathError> {
+        let Some(&first) = points.first() else {
+            return Err(MathError::Degenerate);
+        };
+        let (min, max) = points
+            .iter()
+            .fold((first, first), |(min, max), p| (min.min(*p), max.max(*p)));
+        let tolerance = EPSILON * (max - min).magnitude();
+
+        let faces = ConvexHull::initial_faces(points, tolerance)?;
+        let faces = ConvexHull::expand(points, faces, tolerance);
+        Ok(ConvexHull::compact(points, &faces))
+    }
+
+    /// A tetrahedron of extreme points with every other input point assigned
+    /// to a face it lies in front of.
+    fn initial_faces(points: &[Vector3], tolerance: f32) -> Result<Vec<Face>, MathError> {
+        let all = || 0..points.len();
+        // The lexicographically smallest point is always a hull vertex, and
+        // so is the point farthest from it.
+        let a = all()
+            .min_by(|&i, &j| {
+                let (p, q) = (points[i], points[j]);
+                p.x.total_cmp(&q.x)
+                    .then(p.y.total_cmp(&q.y))
+                    .then(p.z.total_cmp(&q.z))
+            })
+            .ok_or(MathError::Degenerate)?;
+        let origin = points[a];
+        let (b, length) = farthest(
+            all(),
+            points,
+            |i| (points[i] - origin).magnitude(),
+            origin,
+            0.,
+        )
+        .ok_or(MathError::Degenerate)?;
+        if length <= tolerance {
+            return Err(MathError::Degenerate);
+        }
+
+        let ab = points[b] - origin;
+        let (c, line_distance) = farthest(
+            all(),
+            points,
+            |i| (points[i] - origin).reject_from(ab).magnitude(),
+            origin,
+            tolerance,
+        )
+        .ok_or(MathError::Degenerate)?;
+        if line_distance <= tolerance {
+            return Err(MathError::Degenerate);
+        }
+
+        let normal = ab.cross(points[c] - origin).normalize();
+        let (d, plane_distance) = farthest(
+            all(),
+            points,
+            |i| normal.dot(points[i] - origin).abs(),
+            origin,
+            tolerance,
+        )
+        .ok_or(MathError::Degenerate)?;
+        if plane_distance <= tolerance {
+            return Err(MathError::Degenerate);
+        }
+
+        let centroid = (points[a] + points[b] + points[c] + points[d]) * 0.25;
+        let mut faces: Vec<Face> = [[a, b, c], [a, d, b], [a, c, d], [b, d, c]]
+            .into_iter()
+            .map(|[p, q, r]| {
+                let face = Face::new(points, [p, q, r]);
+                if face.distance(centroid) > 0. {
+                    Face::new(points, [p, r, q])
+                } else {
+                    face
+                }
+            })
+            .collect();
+
+        let corners = [a, b, c, d];
+        for i in all().filter(|i| !corners.contains(i)) {
+            if let Some(face) = faces.iter_mut().find(|f| f.distance(points[i]) > tolerance) {
+                face.outside.push(i);
+            }
+        }
+        Ok(faces)
+    }
+
+    /// Adds the farthest outside point of some face until no face has any.
+    fn expand(points: &[Vector3], mut faces: Vec<Face>, tolerance: f32) -> Vec<Face> {
+        while let Some(current) = faces.iter().position(|f| f.alive && !f.outside.is_empty()) {
+            let face = &faces[current];
+            let (eye, _) = farthest(
+                face.outside.iter().copied(),
+                points,
+                |i| face.distance(points[i]),
+                face.anchor,
+                tolerance,
+            )
+            .expect("face has outside points");
+            let eye_point = points[eye];
+
+            let visible: Vec<usize> = (0..faces.len())
+                .filter(|&f| faces[f].alive && faces[f].distance(eye_point) > tolerance)
+                .collect();
+
+            // Edges of the visible region that border a hidden face, keeping
+            // the winding of the visible face they came from.
+            let mut horizon: Vec<[usize; 2]> = Vec::new();
+            for &f in &visible {
+                let v = faces[f].vertices;
+                for edge in [[v[0], v[1]], [v[1], v[2]], [v[2], v[0]]] {
+                    match horizon.iter().position(|e| *e == [edge[1], edge[0]]) {
+                        Some(shared) => {
+                            horizon.swap_remove(shared);
+                        }
+                        None => horizon.push(edge),
+                    }
+                }
+            }
+
+            let mut orphans = Vec::new();
+            for &f in &visible {
+                faces[f].alive = false;
+                orphans.append(&mut faces[f].outside);
+            }
+
+            let first_new = faces.len();
+            faces.extend(
+                horizon
+                    .into_iter()
+                    .map(|[p, q]| Face::new(points, [p, q, eye])),
+            );
+            for i in orphans.into_iter().filter(|&i| i != eye) {
+                // New faces are tried first, as the point was outside a face
+                // they replaced.
+                let (existing, added) = faces.split_at_mut(first_new);
+                if let Some(face) = added
+                    .iter_mut()
+                    .chain(existing.iter_mut().filter(|f| f.alive))
+                    .find(|f| f.distance(points[i]) > tolerance)
+                {
+                    face.outside.push(i);
+                }
+            }
+        }
+        faces
+    }
+
+    /// Drops removed faces and input points that are not hull vertices.
+    fn compact(points: &[Vector3], faces: &[Face]) -> ConvexHull {
+        let mut remap = vec![usize::MAX; points.len()];
+        let mut hull = ConvexHull {
+            vertices: Vec::new(),
+            triangles: Vec::new(),
+            normals: Vec::new(),
+        };
+        for face in faces.iter().filter(|f| f.alive) {
+            let triangle = face.vertices.map(|i| {
+                if remap[i] == usize::MAX {
+                    remap[i] = hull.vertices.len();
+                    hull.vertices.push(points[i]);
+                }
+                remap[i]
+            });
+            hull.triangles.push(triangle);
+            hull.normals.push(face.normal);
+        }
+        hull
+    }
+}
+
+impl SupportMap for ConvexHull {
+    fn support(&self, direction: Vector3) -> Vector3 {
+        self.vertices.support(direction)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::prelude::{Matrix3x3, Quaternion, Vector3};
+
+mod aabb;
+mod bvh;
+mod capsule;
+mod closest;
+mod cylinder;
+mod frustum;
+mod gjk;
+mod hull;
+mod obb;
+mod overlap;
+mod plane;
+mod ray;
+mod raycast;
+mod segment;
+mod sphere;
+mod support;
+mod triangle;
+
+pub use aabb::Aabb;
+pub use bvh::Bvh;
+pub use capsule::Capsule;
+pub use closest::{ClosestPoints, Proximity};
+pub use cylinder::Cylinder;
+pub use frustum::{Containment, DepthRange, Frustum};
+pub use gjk::Separation;
+pub use hull::ConvexHull;
+pub use obb::Obb;
+pub use overlap::{Contact, Interval, Overlap, Side};
+pub use plane::Plane;
+pub use ray::Ray;
+pub use raycast::{RayCast, RayHit, TriangleHit};
+pub use segment::LineSegment;
+pub use sphere::Sphere;
+pub use support::{SupportMap, Transformed};
+pub use triangle::Triangle;
+
+/// Distance below which points are considered to lie on a surface or curve.
+pub(crate) const EPSILON: f32 = 1e-5;
+
+/// Point queries shared by all primitives. Solid shapes contain their interior,
+/// so `closest_point` of an interior point is the point itself.
+pub trait PointQuery {
+    fn contains_point(&self, p: Vector3) -> bool;
+
+    fn closest_point(&self, p: Vector3) -> Vector3;
+
+    fn distance_to_point(&self, p: Vector3) -> f32 {
+        (self.closest_point(p) - p).magnitude()
+    }
+}
+
+/// Moving shapes about the origin.
+pub trait Transform: Sized {
+    /// Applies the linear map `m`. Shapes that cannot represent a sheared or
+    /// non-uniformly scaled copy of themselves return a bound of it instead.
+    fn transform(self, m: Matrix3x3) -> Self;
+
+    fn translate(self, offset: Vector3) -> Self;
+
+    fn rotate(self, q: Quaternion) -> Self {
+        self.transform(q.to_matrix())
+    }
+}
+
+/// Largest factor by which `m` can stretch a vector.
+fn max_scale(m: Matrix3x3) -> f32 {
+    let (_, sigma, _) = m.svd();
+    sigma.x
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,140 @@
+use super::{Aabb, PointQuery, Transform};
+use crate::matrices::product;
+use crate::prelude::{Matrix3x3, Quaternion, Radians, Vector3};
+
+/// Oriented bounding box. The columns of `orientation` are the box axes.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Obb {
+    pub center: Vector3,
+    pub half_extents: Vector3,
+    pub orientation: Matrix3x3,
+}
+
+impl Obb {
+    pub fn new(center: Vector3, half_extents: Vector3, orientation: Matrix3x3) -> Self {
+        Obb {
+            center,
+            half_extents,
+            orientation,
+        }
+    }
+
+    pub fn from_rotation(center: Vector3, half_extents: Vector3, rotation: Quaternion) -> Self {
+        Obb::new(center, half_extents, rotation.to_matrix())
+    }
+
+    /// Tightest box around `points` whose axes are the columns of
+    /// `orientation`, or `None` when there are no points.
+    pub fn fit(points: &[Vector3], orientation: Matrix3x3) -> Option<Self> {
+        let inverse = orientation.transpose();
+        let local: Vec<Vector3> = points.iter().map(|p| inverse * *p).collect();
+        let bounds = Aabb::from_points(&local)?;
+        Some(Obb::new(
+            orientation * bounds.center(),
+            bounds.half_extents(),
+            orientation,
+        ))
+    }
+
+    /// Box aligned with the principal axes of `points`, the eigenvectors of
+    /// their covariance matrix. Returns `None` when there are no points.
+    pub fn from_points(points: &[Vector3]) -> Option<Self> {
+        let mean =
+            points.iter().fold(Vector3::default(), |sum, p| sum + *p) / points.len().max(1) as f32;
+        let covariance = points.iter().fold(Matrix3x3::default(), |sum, p| {
+            let d = *p - mean;
+            sum + Matrix3x3::from_cols(d * d.x, d * d.y, d * d.z)
+        });
+        // The covariance is symmetric positive semi-definite, so its right
+        // singular vectors are its eigenvectors.
+        let (_, _, axes) = covariance.svd();
+        Obb::fit(points, axes)
+    }
+
+    /// [`Obb::from_points`] followed by a local search that rotates the box
+    /// about its own axes while that reduces its volume. Each iteration
+    /// halves the step, starting from 45°.
+    pub fn from_points_refined(points: &[Vector3], iterations: usize) -> Option<Self> {
+        let mut best = Obb::from_points(points)?;
+        let mut step = std::f32::consts::FRAC_PI_4;
+        for _ in 0..iterations {
+            for axis in 0..3 {
+                for angle in [step, -step] {
+                    let turn = match axis {
+                        0 => Matrix3x3::rotation_x(Radians(angle)),
+                        1 => Matrix3x3::rotation_y(Radians(angle)),
+                        _ => Matrix3x3::rotation_z(Radians(angle)),
+                    };
+                    let candidate = Obb::fit(points, product(best.orientation, turn))?;
+                    if candidate.volume() < best.volume() {
+                        best = candidate;
+                    }
+                }
+            }
+            step *= 0.5;
+        }
+        Some(best)
+    }
+
+    pub fn rotation(self) -> Quaternion {
+        Quaternion::from_matrix(self.orientation)
+    }
+
+    pub fn volume(self) -> f32 {
+        let e = self.half_extents;
+        8. * e.x * e.y * e.z
+    }
+
+    pub fn axis(self, i: usize) -> Vector3 {
+        self.orientation.col(i)
+    }
+
+    /// `p` in box coordinates, where the box spans `[-half_extents, half_extents]`.
+    pub fn to_local(self, p: Vector3) -> Vector3 {
+        self.orientation.transpose() * (p - self.center)
+    }
+
+    pub fn to_world(self, p: Vector3) -> Vector3 {
+        self.orientation * p + self.center
+    }
+
+    /// The box in its own coordinates.
+    pub fn local_aabb(self) -> Aabb {
+        Aabb::from_center_half_extents(Vector3::default(), self.half_extents)
+    }
+
+    pub fn corners(self) -> [Vector3; 8] {
+        self.local_aabb().corners().map(|c| self.to_world(c))
+    }
+}
+
+impl From<Aabb> for Obb {
+    fn from(value: Aabb) -> Self {
+        Obb::new(value.center(), value.half_extents(), Matrix3x3::identity())
+    }
+}
+
+impl PointQuery for Obb {
+    fn contains_point(&self, p: Vector3) -> bool {
+        self.local_aabb().contains_point(self.to_local(p))
+    }
+
+    fn closest_point(&self, p: Vector3) -> Vector3 {
+        self.to_world(self.local_aabb().closest_point(self.to_local(p)))
+    }
+}
+
+impl Transform for Obb {
+    /// Rotations keep the box exact; other maps fold their stretch into the
+    /// half extents through a polar decomposition, which bounds the result.
+    fn transform(self, m: Matrix3x3) -> Self {
+        let (rotation, stretch) = product(m, self.orientation).polar_decomposition();
+        let box_stretch = Aabb::from_center_half_extents(Vector3::default(), self.half_extents)
+            .transform(stretch);
+        Obb::new(m * self.center, box_stretch.half_extents(), rotation)
+    }
+
+    fn translate(self, offset: Vector3) -> Self {
+        Obb::new(self.center + offset, self.half_extents, self.orientation)
+    }
+}
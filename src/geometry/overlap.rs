@@ -0,0 +1,292 @@
+use super::{Aabb, Capsule, Cylinder, LineSegment, Obb, Plane, PointQuery, Sphere, Triangle};
+use crate::prelude::Vector3;
+
+/// Cross products shorter than this are skipped as separating axes.
+const AXIS_EPSILON: f32 = 1e-6;
+
+/// Penetration between two overlapping shapes. `normal` is a unit vector
+/// pointing from the first shape towards the second; translating the second
+/// shape by `normal * depth` separates them.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Contact {
+    pub normal: Vector3,
+    pub depth: f32,
+}
+
+impl Contact {
+    /// The same contact seen from the other shape.
+    pub fn flip(self) -> Contact {
+        Contact {
+            normal: -self.normal,
+            depth: self.depth,
+        }
+    }
+}
+
+/// Overlap test against shapes of type `T`. Touching shapes overlap with a
+/// depth of zero.
+pub trait Overlap<T> {
+    fn contact(&self, other: &T) -> Option<Contact>;
+
+    fn overlaps(&self, other: &T) -> bool {
+        self.contact(other).is_some()
+    }
+}
+
+/// Projection of a shape onto an axis.
+pub trait Interval {
+    /// `(min, max)` of `axis.dot(p)` over all points `p` of the shape.
+    fn interval(&self, axis: Vector3) -> (f32, f32);
+}
+
+/// Position of a shape relative to a [`Plane`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Side {
+    Front,
+    Back,
+    Intersecting,
+}
+
+impl Plane {
+    pub fn classify<T: Interval>(self, shape: &T) -> Side {
+        let (min, max) = shape.interval(self.normal);
+        if min > self.distance {
+            Side::Front
+        } else if max < self.distance {
+            Side::Back
+        } else {
+            Side::Intersecting
+        }
+    }
+
+    /// Contact with the solid half-space behind the plane.
+    pub fn contact<T: Interval>(self, shape: &T) -> Option<Contact> {
+        let (min, _) = shape.interval(self.normal);
+        (min <= self.distance).then_some(Contact {
+            normal: self.normal,
+            depth: self.distance - min,
+        })
+    }
+}
+
+fn points_interval(points: &[Vector3], axis: Vector3) -> (f32, f32) {
+    points
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), p| {
+            let d = axis.dot(*p);
+            (min.min(d), max.max(d))
+        })
+}
+
+impl Interval for Sphere {
+    fn interval(&self, axis: Vector3) -> (f32, f32) {
+        let c = axis.dot(self.center);
+        let r = self.radius * axis.magnitude();
+        (c - r, c + r)
+    }
+}
+
+impl Interval for Aabb {
+    fn interval(&self, axis: Vector3) -> (f32, f32) {
+        let c = axis.dot(self.center());
+        let r = axis.abs().dot(self.half_extents());
+        (c - r, c + r)
+    }
+}
+
+impl Interval for Obb {
+    fn interval(&self, axis: Vector3) -> (f32, f32) {
+        let c = axis.dot(self.center);
+        let local = self.orientation.transpose() * axis;
+        let r = local.abs().dot(self.half_extents);
+        (c - r, c + r)
+    }
+}
+
+impl Interval for Triangle {
+    fn interval(&self, axis: Vector3) -> (f32, f32) {
+        points_interval(&[self.a, self.b, self.c], axis)
+    }
+}
+
+impl Interval for LineSegment {
+    fn interval(&self, axis: Vector3) -> (f32, f32) {
+        points_interval(&[self.start, self.end], axis)
+    }
+}
+
+impl Interval for Capsule {
+    fn interval(&self, axis: Vector3) -> (f32, f32) {
+        let (min, max) = self.segment().interval(axis);
+        let r = self.radius * axis.magnitude();
+        (min - r, max + r)
+    }
+}
+
+impl Interval for Cylinder {
+    fn interval(&self, axis: Vector3) -> (f32, f32) {
+        let (min, max) = self.axis().interval(axis);
+        // Half the width of the end discs along `axis`.
+        let r = self.radius * axis.reject_from(self.end - self.start).magnitude();
+        (min - r, max + r)
+    }
+}
+
+/// Separating axis test over `axes`. Returns the axis of least penetration,
+/// oriented from `a` towards `b`.
+fn separating_axis<A: Interval, B: Interval>(
+    a: &A,
+    b: &B,
+    axes: impl IntoIterator<Item = Vector3>,
+) -> Option<Contact> {
+    let mut best: Option<Contact> = None;
+    for axis in axes {
+        let length_squared = axis.magnitude_squared();
+        if length_squared <= AXIS_EPSILON * AXIS_EPSILON {
+            continue;
+        }
+        let axis = axis / length_squared.sqrt();
+        let (a_min, a_max) = a.interval(axis);
+        let (b_min, b_max) = b.interval(axis);
+        let forward = a_max - b_min;
+        let backward = b_max - a_min;
+        if forward < 0. || backward < 0. {
+            return None;
+        }
+        let contact = if forward <= backward {
+            Contact {
+                normal: axis,
+                depth: forward,
+            }
+        } else {
+            Contact {
+                normal: -axis,
+                depth: backward,
+            }
+        };
+        if best.is_none_or(|b| contact.depth < b.depth) {
+            best = Some(contact);
+        }
+    }
+    best
+}
+
+/// Contact of a sphere with a shape given the shape's closest point to the
+/// sphere's center. `fallback` is used as the normal when the center lies on
+/// the shape.
+fn sphere_contact(sphere: &Sphere, closest: Vector3, fallback: Vector3) -> Option<Contact> {
+    let offset = closest - sphere.center;
+    let distance_squared = offset.magnitude_squared();
+    if distance_squared > sphere.radius * sphere.radius {
+        return None;
+    }
+    let distance = distance_squared.sqrt();
+    let normal = if distance > 0. {
+        offset / distance
+    } else {
+        fallback
+    };
+    Some(Contact {
+        normal,
+        depth: sphere.radius - distance,
+    })
+}
+
+fn axes() -> [Vector3; 3] {
+    [
+        Vector3::new(1., 0., 0.),
+        Vector3::new(0., 1., 0.),
+        Vector3::new(0., 0., 1.),
+    ]
+}
+
+impl Overlap<Sphere> for Sphere {
+    fn contact(&self, other: &Sphere) -> Option<Contact> {
+        let grown = Sphere::new(self.center, self.radius + other.radius);
+        sphere_contact(&grown, other.center, Vector3::new(1., 0., 0.))
+    }
+}
+
+impl Overlap<Aabb> for Sphere {
+    fn contact(&self, other: &Aabb) -> Option<Contact> {
+        if !other.contains_point(self.center) {
+            return sphere_contact(self, other.closest_point(self.center), Vector3::default());
+        }
+        // The center is inside: push the box out through its nearest face.
+        let to_min = self.center - other.min;
+        let to_max = other.max - self.center;
+        let (depth, normal) = axes()
+            .into_iter()
+            .enumerate()
+            .flat_map(|(i, axis)| [(to_min[i], axis), (to_max[i], -axis)])
+            .min_by(|a, b| a.0.total_cmp(&b.0))?;
+        Some(Contact {
+            normal,
+            depth: depth + self.radius,
+        })
+    }
+}
+
+impl Overlap<Sphere> for Aabb {
+    fn contact(&self, other: &Sphere) -> Option<Contact> {
+        other.contact(self).map(Contact::flip)
+    }
+}
+
+impl Overlap<Aabb> for Aabb {
+    fn contact(&self, other: &Aabb) -> Option<Contact> {
+        separating_axis(self, other, axes())
+    }
+}
+
+impl Overlap<Triangle> for Aabb {
+    /// Akenine-Möller's 13 axes: the box faces, the triangle normal and the
+    /// cross products of box and triangle edges.
+    fn contact(&self, other: &Triangle) -> Option<Contact> {
+        let edges = [other.b - other.a, other.c - other.b, other.a - other.c];
+        let normal = edges[0].cross(edges[1]);
+        let crosses = axes()
+            .into_iter()
+            .flat_map(|axis| edges.map(|edge| axis.cross(edge)));
+        separating_axis(
+            self,
+            other,
+            axes().into_iter().chain([normal]).chain(crosses),
+        )
+    }
+}
+
+impl Overlap<Aabb> for Triangle {
+    fn contact(&self, other: &Aabb) -> Option<Contact> {
+        other.contact(self).map(Contact::flip)
+    }
+}
+
+impl Overlap<Obb> for Obb {
+    /// The 15 axes of Gottschalk et al.: both sets of box axes and their
+    /// pairwise cross products.
+    fn contact(&self, other: &Obb) -> Option<Contact> {
+        let a = [0, 1, 2].map(|i| self.axis(i));
+        let b = [0, 1, 2].map(|i| other.axis(i));
+        let crosses = a.into_iter().flat_map(|u| b.map(|v| u.cross(v)));
+        separating_axis(self, other, a.into_iter().chain(b).chain(crosses))
+    }
+}
+
+impl Overlap<Triangle> for Sphere {
+    fn contact(&self, other: &Triangle) -> Option<Contact> {
+        let normal = (other.b - other.a).cross(other.c - other.a);
+        let fallback = if normal.magnitude_squared() > 0. {
+            normal.normalize()
+        } else {
+            Vector3::new(1., 0., 0.)
+        };
+        sphere_contact(self, other.closest_point(self.center), fallback)
+    }
+}
+
+impl Overlap<Sphere> for Triangle {
+    fn contact(&self, other: &Sphere) -> Option<Contact> {
+        other.contact(self).map(Contact::flip)
+    }
+}
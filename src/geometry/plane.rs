@@ -0,0 +1,93 @@
+use super::{PointQuery, EPSILON};
+use crate::prelude::{MathError, Matrix3x3, Quaternion, Vector3};
+
+/// Points `x` with `normal.dot(x) == distance`. `normal` is kept unit length
+/// and points to the front side.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Plane {
+    pub normal: Vector3,
+    pub distance: f32,
+}
+
+impl Plane {
+    /// Plane `normal.dot(x) == distance`, with both sides scaled so that the
+    /// normal is unit length. Fails when `normal` is zero.
+    pub fn new(normal: Vector3, distance: f32) -> Result<Self, MathError> {
+        let length = normal.magnitude();
+        if length == 0. {
+            return Err(MathError::Degenerate);
+        }
+        Ok(Plane {
+            normal: normal / length,
+            distance: distance / length,
+        })
+    }
+
+    /// Plane through `point` facing `normal`. Fails when `normal` is zero.
+    pub fn from_point_normal(point: Vector3, normal: Vector3) -> Result<Self, MathError> {
+        Plane::new(normal, normal.dot(point))
+    }
+
+    /// Plane through `a`, `b` and `c`, facing the side from which they appear
+    /// counter-clockwise. Fails when the points are collinear.
+    pub fn from_points(a: Vector3, b: Vector3, c: Vector3) -> Result<Self, MathError> {
+        let normal = (b - a).cross(c - a);
+        let scale = (b - a).magnitude_squared().max((c - a).magnitude_squared());
+        if normal.magnitude_squared() <= EPSILON * EPSILON * scale * scale || scale == 0. {
+            return Err(MathError::Degenerate);
+        }
+        Plane::from_point_normal(a, normal)
+    }
+
+    /// Positive in front of the plane, negative behind it.
+    pub fn signed_distance(self, p: Vector3) -> f32 {
+        self.normal.dot(p) - self.distance
+    }
+
+    pub fn flip(self) -> Plane {
+        Plane {
+            normal: -self.normal,
+            distance: -self.distance,
+        }
+    }
+
+    /// Applies the linear map `m`. Unlike the [`Transform`](super::Transform)
+    /// shapes this can fail: a singular `m` may collapse the normal to zero,
+    /// which gives [`MathError::Degenerate`].
+    pub fn transform(self, m: Matrix3x3) -> Result<Plane, MathError> {
+        let point = m * (self.normal * self.distance);
+        let (t, b) = self.normal.orthonormal_basis();
+        // Normals transform by the inverse transpose; `(M t) × (M b)` is that
+        // up to the sign of the determinant.
+        let normal = (m * t).cross(m * b) * m.determinant().signum();
+        Plane::from_point_normal(point, normal)
+    }
+
+    pub fn translate(self, offset: Vector3) -> Plane {
+        Plane {
+            normal: self.normal,
+            distance: self.distance + self.normal.dot(offset),
+        }
+    }
+
+    pub fn rotate(self, q: Quaternion) -> Plane {
+        Plane {
+            normal: q.rotate(self.normal),
+            distance: self.distance,
+        }
+    }
+}
+
+impl PointQuery for Plane {
+    fn contains_point(&self, p: Vector3) -> bool {
+        self.signed_distance(p).abs() <= EPSILON
+    }
+
+    fn closest_point(&self, p: Vector3) -> Vector3 {
+        p - self.normal * self.signed_distance(p)
+    }
+
+    fn distance_to_point(&self, p: Vector3) -> f32 {
+        self.signed_distance(p).abs()
+    }
+}
@@ -0,0 +1,43 @@
+use super::{PointQuery, Transform, EPSILON};
+use crate::prelude::{Matrix3x3, Vector3};
+
+/// Half-line starting at `origin`. `direction` is kept unit length.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Ray {
+    pub origin: Vector3,
+    pub direction: Vector3,
+}
+
+impl Ray {
+    pub fn new(origin: Vector3, direction: Vector3) -> Self {
+        Ray {
+            origin,
+            direction: direction.normalize(),
+        }
+    }
+
+    pub fn at(self, t: f32) -> Vector3 {
+        self.origin + self.direction * t
+    }
+}
+
+impl PointQuery for Ray {
+    fn contains_point(&self, p: Vector3) -> bool {
+        self.distance_to_point(p) <= EPSILON
+    }
+
+    fn closest_point(&self, p: Vector3) -> Vector3 {
+        let t = (p - self.origin).dot(self.direction).max(0.);
+        self.at(t)
+    }
+}
+
+impl Transform for Ray {
+    fn transform(self, m: Matrix3x3) -> Self {
+        Ray::new(m * self.origin, m * self.direction)
+    }
+
+    fn translate(self, offset: Vector3) -> Self {
+        Ray::new(self.origin + offset, self.direction)
+    }
+}
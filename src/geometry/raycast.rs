@@ -0,0 +1,278 @@
+use super::{Aabb, Capsule, Cylinder, Obb, Plane, Ray, Sphere, Triangle};
+use crate::prelude::Vector3;
+
+/// Relative tolerance below which a ray is treated as parallel to a surface.
+const PARALLEL_EPSILON: f32 = 1e-7;
+
+/// First surface crossing along a ray. `normal` is the unit outward normal of
+/// the surface at `point`; for a ray starting inside a solid that is the exit
+/// point.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct RayHit {
+    pub distance: f32,
+    pub point: Vector3,
+    pub normal: Vector3,
+}
+
+/// [`RayHit`] on a triangle with the barycentric coordinates of the hit point
+/// relative to `a`, `b` and `c`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TriangleHit {
+    pub hit: RayHit,
+    pub barycentric: Vector3,
+}
+
+/// Shapes that a [`Ray`] can hit.
+pub trait RayCast {
+    fn ray_cast(&self, ray: Ray) -> Option<RayHit>;
+}
+
+impl Ray {
+    /// Nearest hit at a non-negative distance.
+    pub fn intersect<T: RayCast>(self, shape: &T) -> Option<RayHit> {
+        shape.ray_cast(self)
+    }
+
+    /// Möller–Trumbore. Both sides of the triangle are hit; the normal
+    /// follows the counter-clockwise winding of `a`, `b`, `c`.
+    pub fn intersect_triangle(self, triangle: Triangle) -> Option<TriangleHit> {
+        let e1 = triangle.b - triangle.a;
+        let e2 = triangle.c - triangle.a;
+        let p = self.direction.cross(e2);
+        let det = e1.dot(p);
+        let normal = e1.cross(e2);
+        if det.abs() <= PARALLEL_EPSILON * normal.magnitude() {
+            return None;
+        }
+
+        let inv_det = 1. / det;
+        let s = self.origin - triangle.a;
+        let u = s.dot(p) * inv_det;
+        if !(0. ..=1.).contains(&u) {
+            return None;
+        }
+        let q = s.cross(e1);
+        let v = self.direction.dot(q) * inv_det;
+        if v < 0. || u + v > 1. {
+            return None;
+        }
+        let t = e2.dot(q) * inv_det;
+        if t < 0. {
+            return None;
+        }
+
+        Some(TriangleHit {
+            hit: self.hit(t, normal.normalize()),
+            barycentric: Vector3::new(1. - u - v, u, v),
+        })
+    }
+
+    fn hit(self, distance: f32, normal: Vector3) -> RayHit {
+        RayHit {
+            distance,
+            point: self.at(distance),
+            normal,
+        }
+    }
+
+    /// Distances where the line of the ray crosses a sphere, in increasing
+    /// order. Either may be negative.
+    fn sphere_roots(self, center: Vector3, radius: f32) -> Option<(f32, f32)> {
+        let m = self.origin - center;
+        let b = m.dot(self.direction);
+        // Project the origin onto the perpendicular through the center first,
+        // which keeps the discriminant accurate for distant spheres.
+        let closest = m - self.direction * b;
+        let discriminant = radius * radius - closest.magnitude_squared();
+        if discriminant < 0. {
+            return None;
+        }
+        let root = discriminant.sqrt();
+        Some((-b - root, -b + root))
+    }
+
+    /// Crossings of the curved side of the finite cylinder around `start..end`.
+    fn cylinder_side_hits(self, start: Vector3, end: Vector3, radius: f32) -> [Option<RayHit>; 2] {
+        let axis = end - start;
+        let length_squared = axis.magnitude_squared();
+        if length_squared == 0. {
+            return [None, None];
+        }
+        let m = (self.origin - start).reject_from(axis);
+        let d = self.direction.reject_from(axis);
+        let a = d.magnitude_squared();
+        // Parallel to the axis: the side is never crossed, only grazed.
+        if a <= PARALLEL_EPSILON {
+            return [None, None];
+        }
+        let b = m.dot(d);
+        let c = m.magnitude_squared() - radius * radius;
+        let discriminant = b * b - a * c;
+        if discriminant < 0. {
+            return [None, None];
+        }
+        let root = discriminant.sqrt();
+        [(-b - root) / a, (-b + root) / a].map(|t| {
+            let point = self.at(t);
+            let s = (point - start).dot(axis);
+            if t < 0. || s < 0. || s > length_squared {
+                return None;
+            }
+            Some(self.hit(t, (point - start).reject_from(axis).normalize()))
+        })
+    }
+}
+
+fn nearest(hits: impl IntoIterator<Item = Option<RayHit>>) -> Option<RayHit> {
+    hits.into_iter()
+        .flatten()
+        .min_by(|a, b| a.distance.total_cmp(&b.distance))
+}
+
+impl RayCast for Plane {
+    /// Rays parallel to the plane miss it, including rays lying in it.
+    fn ray_cast(&self, ray: Ray) -> Option<RayHit> {
+        let denom = self.normal.dot(ray.direction);
+        if denom.abs() <= PARALLEL_EPSILON {
+            return None;
+        }
+        let t = -self.signed_distance(ray.origin) / denom;
+        (t >= 0.).then(|| ray.hit(t, self.normal))
+    }
+}
+
+impl RayCast for Sphere {
+    fn ray_cast(&self, ray: Ray) -> Option<RayHit> {
+        let (near, far) = ray.sphere_roots(self.center, self.radius)?;
+        let t = if near >= 0. { near } else { far };
+        if t < 0. {
+            return None;
+        }
+        let point = ray.at(t);
+        let normal = if self.radius > 0. {
+            (point - self.center) / self.radius
+        } else {
+            -ray.direction
+        };
+        Some(RayHit {
+            distance: t,
+            point,
+            normal,
+        })
+    }
+}
+
+/// Entry and exit of a ray through the slabs of a box, as `(distance, axis)`
+/// pairs. Axes the ray runs parallel to are handled explicitly rather than
+/// relying on infinite reciprocals. The entry may lie behind the origin.
+pub(super) fn slabs(ray: Ray, aabb: &Aabb) -> Option<((f32, usize), (f32, usize))> {
+    let mut entry = (f32::NEG_INFINITY, 0);
+    let mut exit = (f32::INFINITY, 0);
+
+    for axis in 0..3 {
+        let (o, d) = (ray.origin[axis], ray.direction[axis]);
+        let (lo, hi) = (aabb.min[axis], aabb.max[axis]);
+        if d.abs() <= PARALLEL_EPSILON {
+            if o < lo || o > hi {
+                return None;
+            }
+            continue;
+        }
+        let (t0, t1) = ((lo - o) / d, (hi - o) / d);
+        let (near, far) = if t0 <= t1 { (t0, t1) } else { (t1, t0) };
+        if near > entry.0 {
+            entry = (near, axis);
+        }
+        if far < exit.0 {
+            exit = (far, axis);
+        }
+        if entry.0 > exit.0 {
+            return None;
+        }
+    }
+    (exit.0 >= 0.).then_some((entry, exit))
+}
+
+impl RayCast for Aabb {
+    /// Slab method.
+    fn ray_cast(&self, ray: Ray) -> Option<RayHit> {
+        let ((t_min, entry_axis), (t_max, exit_axis)) = slabs(ray, self)?;
+        // A ray parallel to every axis cannot exist, so one bound is finite.
+        let (t, axis, sign) = if t_min >= 0. {
+            (t_min, entry_axis, -ray.direction[entry_axis].signum())
+        } else {
+            (t_max, exit_axis, ray.direction[exit_axis].signum())
+        };
+        let mut normal = Vector3::default();
+        match axis {
+            0 => normal.x = sign,
+            1 => normal.y = sign,
+            _ => normal.z = sign,
+        }
+        Some(ray.hit(t, normal))
+    }
+}
+
+impl RayCast for Triangle {
+    fn ray_cast(&self, ray: Ray) -> Option<RayHit> {
+        ray.intersect_triangle(*self).map(|hit| hit.hit)
+    }
+}
+
+impl RayCast for Capsule {
+    /// The side of the capsule and the two end caps, keeping only the
+    /// hemisphere of each cap that lies outside the side.
+    fn ray_cast(&self, ray: Ray) -> Option<RayHit> {
+        let axis = self.end - self.start;
+        let cap = |center: Vector3, outward: f32| {
+            let (near, far) = ray.sphere_roots(center, self.radius)?;
+            [near, far]
+                .into_iter()
+                .filter(|t| *t >= 0.)
+                .map(|t| (t, ray.at(t)))
+                .find(|(_, p)| (*p - center).dot(axis) * outward >= 0.)
+                .map(|(t, p)| RayHit {
+                    distance: t,
+                    point: p,
+                    normal: (p - center).normalize(),
+                })
+        };
+
+        let [side_near, side_far] = ray.cylinder_side_hits(self.start, self.end, self.radius);
+        nearest([side_near, side_far, cap(self.start, -1.), cap(self.end, 1.)])
+    }
+}
+
+impl RayCast for Cylinder {
+    fn ray_cast(&self, ray: Ray) -> Option<RayHit> {
+        let axis = self.end - self.start;
+        if axis.magnitude_squared() == 0. {
+            return None;
+        }
+        let disc = |center: Vector3, normal: Vector3| {
+            let hit = Plane::from_point_normal(center, normal)
+                .ok()?
+                .ray_cast(ray)?;
+            ((hit.point - center).magnitude_squared() <= self.radius * self.radius).then_some(hit)
+        };
+
+        let [side_near, side_far] = ray.cylinder_side_hits(self.start, self.end, self.radius);
+        nearest([
+            side_near,
+            side_far,
+            disc(self.start, -axis),
+            disc(self.end, axis),
+        ])
+    }
+}
+
+impl RayCast for Obb {
+    fn ray_cast(&self, ray: Ray) -> Option<RayHit> {
+        let local = Ray {
+            origin: self.to_local(ray.origin),
+            direction: self.orientation.transpose() * ray.direction,
+        };
+        let hit = self.local_aabb().ray_cast(local)?;
+        Some(ray.hit(hit.distance, self.orientation * hit.normal))
+    }
+}
@@ -0,0 +1,52 @@
+use super::{PointQuery, Transform, EPSILON};
+use crate::prelude::{Matrix3x3, Vector3};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct LineSegment {
+    pub start: Vector3,
+    pub end: Vector3,
+}
+
+impl LineSegment {
+    pub fn new(start: Vector3, end: Vector3) -> Self {
+        LineSegment { start, end }
+    }
+
+    pub fn length(self) -> f32 {
+        (self.end - self.start).magnitude()
+    }
+
+    /// Parameter in `[0, 1]` of the point closest to `p`.
+    pub fn closest_parameter(self, p: Vector3) -> f32 {
+        let d = self.end - self.start;
+        let length_squared = d.magnitude_squared();
+        if length_squared == 0. {
+            return 0.;
+        }
+        ((p - self.start).dot(d) / length_squared).clamp(0., 1.)
+    }
+
+    pub fn at(self, t: f32) -> Vector3 {
+        self.start.lerp(self.end, t)
+    }
+}
+
+impl PointQuery for LineSegment {
+    fn contains_point(&self, p: Vector3) -> bool {
+        self.distance_to_point(p) <= EPSILON
+    }
+
+    fn closest_point(&self, p: Vector3) -> Vector3 {
+        self.at(self.closest_parameter(p))
+    }
+}
+
+impl Transform for LineSegment {
+    fn transform(self, m: Matrix3x3) -> Self {
+        LineSegment::new(m * self.start, m * self.end)
+    }
+
+    fn translate(self, offset: Vector3) -> Self {
+        LineSegment::new(self.start + offset, self.end + offset)
+    }
+}
@@ -0,0 +1,139 @@
+use super::{max_scale, PointQuery, Transform, Triangle};
+use crate::prelude::{Matrix3x3, Quaternion, Vector3};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Sphere {
+    pub center: Vector3,
+    pub radius: f32,
+}
+
+/// Relative slack when testing whether a point lies in a candidate sphere.
+const CONTAINS_TOLERANCE: f32 = 1e-5;
+
+impl Sphere {
+    pub fn new(center: Vector3, radius: f32) -> Self {
+        Sphere { center, radius }
+    }
+
+    /// Smallest sphere containing `points` by Welzl's algorithm, or `None`
+    /// when there are no points. Points are visited in a fixed shuffled
+    /// order so the expected running time stays linear for sorted input.
+    pub fn from_points(points: &[Vector3]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+        let mut shuffled = points.to_vec();
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        for i in (1..shuffled.len()).rev() {
+            // xorshift64
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            shuffled.swap(i, (state % (i as u64 + 1)) as usize);
+        }
+        Some(welzl(&shuffled, &mut Vec::with_capacity(4)))
+    }
+
+    fn encloses(self, p: Vector3) -> bool {
+        (p - self.center).magnitude()
+            <= self.radius * (1. + CONTAINS_TOLERANCE) + CONTAINS_TOLERANCE
+    }
+}
+
+/// Smallest sphere containing `points` with every point of `boundary` on its
+/// surface. The recursion depth is bounded by the four boundary points.
+fn welzl(points: &[Vector3], boundary: &mut Vec<Vector3>) -> Sphere {
+    let mut sphere = sphere_through(boundary);
+    if boundary.len() == 4 {
+        return sphere;
+    }
+    for (i, p) in points.iter().enumerate() {
+        if sphere.encloses(*p) {
+            continue;
+        }
+        boundary.push(*p);
+        sphere = welzl(&points[..i], boundary);
+        boundary.pop();
+    }
+    sphere
+}
+
+/// Smallest sphere with up to four points on its surface. Degenerate sets
+/// fall back to the smallest sphere through a subset that contains the rest.
+fn sphere_through(boundary: &[Vector3]) -> Sphere {
+    match *boundary {
+        [] => Sphere::new(Vector3::default(), 0.),
+        [a] => Sphere::new(a, 0.),
+        [a, b] => Sphere::new((a + b) * 0.5, (b - a).magnitude() * 0.5),
+        [a, b, c] => {
+            let triangle = Triangle::new(a, b, c);
+            let (ab, ac) = (b - a, c - a);
+            let denominator = 2. * triangle.weighted_normal().magnitude_squared();
+            if denominator <= f32::EPSILON * ab.magnitude_squared() * ac.magnitude_squared() {
+                return smallest_enclosing_subset(boundary);
+            }
+            let center = triangle.circumcenter();
+            Sphere::new(center, (a - center).magnitude())
+        }
+        [a, b, c, d] => {
+            let (ab, ac, ad) = (b - a, c - a, d - a);
+            let rows = Matrix3x3::from_rows(ab * 2., ac * 2., ad * 2.);
+            let rhs = Vector3::new(
+                ab.magnitude_squared(),
+                ac.magnitude_squared(),
+                ad.magnitude_squared(),
+            );
+            match rows.lu().solve(rhs) {
+                Ok(offset) => Sphere::new(a + offset, offset.magnitude()),
+                Err(_) => smallest_enclosing_subset(boundary),
+            }
+        }
+        _ => unreachable!("a sphere is fixed by at most four points"),
+    }
+}
+
+fn smallest_enclosing_subset(points: &[Vector3]) -> Sphere {
+    let n = points.len();
+    // Every proper subset by bitmask, excluding the empty set.
+    (1..(1u32 << n) - 1)
+        .map(|mask| {
+            let subset: Vec<Vector3> = (0..n)
+                .filter(|i| mask & (1 << i) != 0)
+                .map(|i| points[i])
+                .collect();
+            sphere_through(&subset)
+        })
+        .filter(|s| points.iter().all(|p| s.encloses(*p)))
+        .min_by(|a, b| a.radius.total_cmp(&b.radius))
+        .unwrap_or_else(|| Sphere::new(points[0], 0.))
+}
+
+impl PointQuery for Sphere {
+    fn contains_point(&self, p: Vector3) -> bool {
+        (p - self.center).magnitude_squared() <= self.radius * self.radius
+    }
+
+    fn closest_point(&self, p: Vector3) -> Vector3 {
+        let offset = p - self.center;
+        let distance = offset.magnitude();
+        if distance <= self.radius {
+            return p;
+        }
+        self.center + offset * (self.radius / distance)
+    }
+}
+
+impl Transform for Sphere {
+    /// The result bounds the transformed sphere when `m` is not a similarity.
+    fn transform(self, m: Matrix3x3) -> Self {
+        Sphere::new(m * self.center, self.radius * max_scale(m))
+    }
+
+    fn translate(self, offset: Vector3) -> Self {
+        Sphere::new(self.center + offset, self.radius)
+    }
+
+    fn rotate(self, q: Quaternion) -> Self {
+        Sphere::new(q.rotate(self.center), self.radius)
+    }
+}
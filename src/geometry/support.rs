@@ -0,0 +1,142 @@
+use super::gjk::{self, Separation};
+use super::{Aabb, Capsule, Contact, Cylinder, LineSegment, Obb, Sphere, Triangle};
+use crate::prelude::{Quaternion, Vector3};
+
+/// Convex shapes described by their support function, as used by GJK and EPA.
+pub trait SupportMap {
+    /// A point of the shape that is farthest along `direction`. `direction`
+    /// need not be unit length.
+    fn support(&self, direction: Vector3) -> Vector3;
+
+    /// Closest points computed with GJK, or `None` when the shapes intersect.
+    fn separation<B: SupportMap + ?Sized>(&self, other: &B) -> Option<Separation> {
+        gjk::separation(self, other)
+    }
+
+    fn intersects<B: SupportMap + ?Sized>(&self, other: &B) -> bool {
+        self.separation(other).is_none()
+    }
+
+    /// Penetration depth and direction computed with EPA, or `None` when the
+    /// shapes are disjoint. The contact follows the [`Contact`] convention,
+    /// pointing from `self` to `other`.
+    fn penetration<B: SupportMap + ?Sized>(&self, other: &B) -> Option<Contact> {
+        gjk::penetration(self, other)
+    }
+}
+
+/// A shape rotated by `rotation` and then moved by `translation`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Transformed<T> {
+    pub shape: T,
+    pub rotation: Quaternion,
+    pub translation: Vector3,
+}
+
+impl<T> Transformed<T> {
+    pub fn new(shape: T, rotation: Quaternion, translation: Vector3) -> Self {
+        Transformed {
+            shape,
+            rotation: rotation.normalized(),
+            translation,
+        }
+    }
+}
+
+impl<T: SupportMap> SupportMap for Transformed<T> {
+    fn support(&self, direction: Vector3) -> Vector3 {
+        let local = self.rotation.conjugate().rotate(direction);
+        self.rotation.rotate(self.shape.support(local)) + self.translation
+    }
+}
+
+fn farthest(points: &[Vector3], direction: Vector3) -> Vector3 {
+    points
+        .iter()
+        .copied()
+        .max_by(|a, b| a.dot(direction).total_cmp(&b.dot(direction)))
+        .unwrap_or_default()
+}
+
+fn unit_or_zero(v: Vector3) -> Vector3 {
+    let length = v.magnitude();
+    if length > 0. {
+        v / length
+    } else {
+        Vector3::default()
+    }
+}
+
+/// The convex hull of a set of points.
+impl SupportMap for [Vector3] {
+    fn support(&self, direction: Vector3) -> Vector3 {
+        farthest(self, direction)
+    }
+}
+
+impl SupportMap for Vec<Vector3> {
+    fn support(&self, direction: Vector3) -> Vector3 {
+        farthest(self, direction)
+    }
+}
+
+impl SupportMap for Sphere {
+    fn support(&self, direction: Vector3) -> Vector3 {
+        self.center + unit_or_zero(direction) * self.radius
+    }
+}
+
+impl SupportMap for Aabb {
+    fn support(&self, direction: Vector3) -> Vector3 {
+        Vector3::new(
+            if direction.x >= 0. {
+                self.max.x
+            } else {
+                self.min.x
+            },
+            if direction.y >= 0. {
+                self.max.y
+            } else {
+                self.min.y
+            },
+            if direction.z >= 0. {
+                self.max.z
+            } else {
+                self.min.z
+            },
+        )
+    }
+}
+
+impl SupportMap for Obb {
+    fn support(&self, direction: Vector3) -> Vector3 {
+        let local = self.orientation.transpose() * direction;
+        self.to_world(self.local_aabb().support(local))
+    }
+}
+
+impl SupportMap for Triangle {
+    fn support(&self, direction: Vector3) -> Vector3 {
+        farthest(&[self.a, self.b, self.c], direction)
+    }
+}
+
+impl SupportMap for LineSegment {
+    fn support(&self, direction: Vector3) -> Vector3 {
+        farthest(&[self.start, self.end], direction)
+    }
+}
+
+impl SupportMap for Capsule {
+    fn support(&self, direction: Vector3) -> Vector3 {
+        self.segment().support(direction) + unit_or_zero(direction) * self.radius
+    }
+}
+
+impl SupportMap for Cylinder {
+    fn support(&self, direction: Vector3) -> Vector3 {
+        let axis = self.end - self.start;
+        let radial = unit_or_zero(direction.reject_from(axis));
+        self.axis().support(direction) + radial * self.radius
+    }
+}
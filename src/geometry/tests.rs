@@ -0,0 +1,1473 @@
+use super::*;
+
+use crate::prelude::{MathError, Radians, Vector2};
+
+fn approx_eq(a: Vector3, b: Vector3) -> bool {
+    (a - b).magnitude() < 1e-5
+}
+
+fn quarter_turn_z() -> Quaternion {
+    Quaternion::from_axis_angle(
+        Vector3::new(0., 0., 1.),
+        Radians(std::f32::consts::FRAC_PI_2),
+    )
+}
+
+#[test]
+fn ray_point_queries() {
+    let ray = Ray::new(Vector3::new(0., 0., 0.), Vector3::new(2., 0., 0.));
+
+    assert_eq!(ray.direction, Vector3::new(1., 0., 0.));
+    assert_eq!(ray.at(3.), Vector3::new(3., 0., 0.));
+    assert!(ray.contains_point(Vector3::new(5., 0., 0.)));
+    assert!(!ray.contains_point(Vector3::new(-1., 0., 0.)));
+    assert_eq!(
+        ray.closest_point(Vector3::new(2., 3., 0.)),
+        Vector3::new(2., 0., 0.)
+    );
+    assert_eq!(ray.distance_to_point(Vector3::new(-3., 4., 0.)), 5.);
+}
+
+#[test]
+fn plane_from_points() {
+    let plane = Plane::from_points(
+        Vector3::new(0., 0., 2.),
+        Vector3::new(1., 0., 2.),
+        Vector3::new(0., 1., 2.),
+    )
+    .unwrap();
+
+    assert_eq!(plane.normal, Vector3::new(0., 0., 1.));
+    assert_eq!(plane.distance, 2.);
+    assert_eq!(
+        Plane::from_points(
+            Vector3::new(0., 0., 0.),
+            Vector3::new(1., 1., 1.),
+            Vector3::new(2., 2., 2.)
+        ),
+        Err(MathError::Degenerate)
+    );
+}
+
+#[test]
+fn plane_point_queries() {
+    let plane = Plane::new(Vector3::new(0., 2., 0.), 2.).unwrap();
+    let p = Vector3::new(3., 4., -1.);
+
+    assert_eq!(plane.distance, 1.);
+    assert_eq!(plane.signed_distance(p), 3.);
+    assert_eq!(plane.flip().signed_distance(p), -3.);
+    assert_eq!(plane.closest_point(p), Vector3::new(3., 1., -1.));
+    assert_eq!(plane.distance_to_point(Vector3::new(0., -1., 0.)), 2.);
+    assert!(plane.contains_point(Vector3::new(7., 1., 7.)));
+}
+
+#[test]
+fn plane_rejects_zero_normal() {
+    assert_eq!(
+        Plane::new(Vector3::default(), 1.),
+        Err(MathError::Degenerate)
+    );
+    assert_eq!(
+        Plane::from_point_normal(Vector3::new(1., 0., 0.), Vector3::default()),
+        Err(MathError::Degenerate)
+    );
+
+    let plane = Plane::new(Vector3::new(1., 0., 0.), 1.).unwrap();
+    let flatten = Matrix3x3::from_diagonal(Vector3::new(1., 0., 1.));
+    assert_eq!(plane.transform(flatten), Err(MathError::Degenerate));
+
+    let mut m = projection(
+        std::f32::consts::FRAC_PI_2,
+        2.,
+        1.,
+        10.,
+        DepthRange::ZeroToOne,
+    );
+    m[2] = [0.; 4];
+    assert_eq!(
+        Frustum::from_view_projection(m, DepthRange::ZeroToOne),
+        Err(MathError::Degenerate)
+    );
+}
+
+#[test]
+fn sphere_point_queries() {
+    let sphere = Sphere::new(Vector3::new(1., 0., 0.), 2.);
+
+    assert!(sphere.contains_point(Vector3::new(2., 1., 0.)));
+    assert!(!sphere.contains_point(Vector3::new(4., 0., 0.)));
+    assert_eq!(
+        sphere.closest_point(Vector3::new(6., 0., 0.)),
+        Vector3::new(3., 0., 0.)
+    );
+    assert_eq!(sphere.distance_to_point(Vector3::new(1., 5., 0.)), 3.);
+    assert_eq!(sphere.distance_to_point(Vector3::new(1., 1., 0.)), 0.);
+}
+
+#[test]
+fn aabb_point_queries() {
+    let aabb = Aabb::new(Vector3::new(1., 1., 1.), Vector3::new(-1., -1., -1.));
+
+    assert_eq!(aabb.min, Vector3::new(-1., -1., -1.));
+    assert_eq!(aabb.half_extents(), Vector3::new(1., 1., 1.));
+    assert!(aabb.contains_point(Vector3::new(1., 0., -0.5)));
+    assert!(!aabb.contains_point(Vector3::new(1.1, 0., 0.)));
+    assert_eq!(
+        aabb.closest_point(Vector3::new(3., 0.5, -4.)),
+        Vector3::new(1., 0.5, -1.)
+    );
+    assert_eq!(aabb.distance_to_point(Vector3::new(4., 5., 0.)), 5.);
+}
+
+#[test]
+fn aabb_merge_and_corners() {
+    let a = Aabb::new(Vector3::new(0., 0., 0.), Vector3::new(1., 1., 1.));
+    let b = Aabb::new(Vector3::new(2., -1., 0.), Vector3::new(3., 0., 1.));
+    let merged = a.merge(b);
+
+    assert_eq!(merged.min, Vector3::new(0., -1., 0.));
+    assert_eq!(merged.max, Vector3::new(3., 1., 1.));
+    assert!(a.corners().iter().all(|c| a.contains_point(*c)));
+}
+
+#[test]
+fn segment_point_queries() {
+    let segment = LineSegment::new(Vector3::new(0., 0., 0.), Vector3::new(4., 0., 0.));
+
+    assert_eq!(segment.length(), 4.);
+    assert!(segment.contains_point(Vector3::new(2., 0., 0.)));
+    assert!(!segment.contains_point(Vector3::new(5., 0., 0.)));
+    assert_eq!(
+        segment.closest_point(Vector3::new(1., 2., 0.)),
+        Vector3::new(1., 0., 0.)
+    );
+    assert_eq!(segment.distance_to_point(Vector3::new(7., 4., 0.)), 5.);
+}
+
+#[test]
+fn capsule_point_queries() {
+    let capsule = Capsule::new(Vector3::new(0., 0., 0.), Vector3::new(0., 4., 0.), 1.);
+
+    assert!(capsule.contains_point(Vector3::new(0.5, 2., 0.5)));
+    assert!(capsule.contains_point(Vector3::new(0., 4.9, 0.)));
+    assert!(!capsule.contains_point(Vector3::new(1., 5., 0.)));
+    assert_eq!(
+        capsule.closest_point(Vector3::new(3., 2., 0.)),
+        Vector3::new(1., 2., 0.)
+    );
+    assert_eq!(capsule.distance_to_point(Vector3::new(0., -3., 0.)), 2.);
+}
+
+#[test]
+fn triangle_point_queries() {
+    let triangle = Triangle::new(
+        Vector3::new(0., 0., 0.),
+        Vector3::new(2., 0., 0.),
+        Vector3::new(0., 2., 0.),
+    );
+
+    assert!(triangle.contains_point(Vector3::new(0.5, 0.5, 0.)));
+    assert!(!triangle.contains_point(Vector3::new(0.5, 0.5, 0.1)));
+    assert_eq!(
+        triangle.closest_point(Vector3::new(0.5, 0.5, 3.)),
+        Vector3::new(0.5, 0.5, 0.)
+    );
+    assert_eq!(
+        triangle.closest_point(Vector3::new(-1., -1., 0.)),
+        Vector3::new(0., 0., 0.)
+    );
+    assert_eq!(
+        triangle.closest_point(Vector3::new(1., -1., 0.)),
+        Vector3::new(1., 0., 0.)
+    );
+    assert!(approx_eq(
+        triangle.closest_point(Vector3::new(2., 2., 0.)),
+        Vector3::new(1., 1., 0.)
+    ));
+    assert_eq!(
+        triangle.closest_point(Vector3::new(3., -1., 0.)),
+        Vector3::new(2., 0., 0.)
+    );
+}
+
+#[test]
+fn transform_by_rotation() {
+    let q = quarter_turn_z();
+    let x = Vector3::new(1., 0., 0.);
+    let y = Vector3::new(0., 1., 0.);
+
+    let ray = Ray::new(x, x).rotate(q);
+    assert!(approx_eq(ray.origin, y) && approx_eq(ray.direction, y));
+
+    let plane = Plane::new(x, 2.).unwrap().rotate(q);
+    assert!(approx_eq(plane.normal, y));
+    assert!((plane.distance - 2.).abs() < 1e-5);
+
+    let sphere = Sphere::new(x, 3.).rotate(q);
+    assert!(approx_eq(sphere.center, y));
+    assert_eq!(sphere.radius, 3.);
+
+    let aabb = Aabb::new(Vector3::default(), Vector3::new(2., 1., 1.)).rotate(q);
+    assert!(approx_eq(aabb.min, Vector3::new(-1., 0., 0.)));
+    assert!(approx_eq(aabb.max, Vector3::new(0., 2., 1.)));
+
+    let triangle = Triangle::new(x, y, Vector3::default()).rotate(q);
+    assert!(approx_eq(triangle.a, y) && approx_eq(triangle.b, -x));
+
+    let capsule = Capsule::new(x, x * 2., 0.5).rotate(q);
+    assert!(approx_eq(capsule.end, y * 2.));
+}
+
+#[test]
+fn transform_by_matrix() {
+    let scale = Matrix3x3::from_diagonal(Vector3::new(2., 1., 1.));
+    let x = Vector3::new(1., 0., 0.);
+
+    let sphere = Sphere::new(x, 1.).transform(scale);
+    assert_eq!(sphere.center, Vector3::new(2., 0., 0.));
+    assert!((sphere.radius - 2.).abs() < 1e-5);
+
+    let segment = LineSegment::new(x, x * 2.).transform(scale);
+    assert_eq!(segment.end, Vector3::new(4., 0., 0.));
+
+    let plane = Plane::from_point_normal(x, Vector3::new(1., 1., 0.))
+        .unwrap()
+        .transform(scale)
+        .unwrap();
+    assert!(plane.contains_point(Vector3::new(2., 0., 0.)));
+    assert!(plane.contains_point(Vector3::new(0., 1., 0.)));
+    assert!(plane.signed_distance(Vector3::new(10., 10., 0.)) > 0.);
+
+    let obb = Obb::from(Aabb::new(Vector3::default(), Vector3::new(2., 2., 2.)))
+        .rotate(quarter_turn_z())
+        .transform(scale);
+    assert!(approx_eq(obb.center, Vector3::new(-2., 1., 1.)));
+    assert!(approx_eq(obb.half_extents, Vector3::new(1., 2., 1.)));
+
+    let mirror = Matrix3x3::from_diagonal(Vector3::new(-1., 1., 1.));
+    let mirrored = Plane::new(x, 1.).unwrap().transform(mirror).unwrap();
+    assert!(approx_eq(mirrored.normal, -x));
+    assert!((mirrored.distance - 1.).abs() < 1e-5);
+}
+
+#[test]
+fn translate() {
+    let offset = Vector3::new(1., 2., 3.);
+
+    assert_eq!(
+        Sphere::new(Vector3::default(), 1.).translate(offset).center,
+        offset
+    );
+    assert_eq!(
+        Plane::new(Vector3::new(0., 0., 1.), 1.)
+            .unwrap()
+            .translate(offset)
+            .distance,
+        4.
+    );
+    assert_eq!(
+        Aabb::new(Vector3::default(), offset).translate(offset).max,
+        offset * 2.
+    );
+}
+
+#[test]
+fn cylinder_and_obb_point_queries() {
+    let cylinder = Cylinder::new(Vector3::new(0., 0., 0.), Vector3::new(0., 4., 0.), 1.);
+    assert!(cylinder.contains_point(Vector3::new(0.5, 2., 0.5)));
+    assert!(!cylinder.contains_point(Vector3::new(0., 4.5, 0.)));
+    assert_eq!(
+        cylinder.closest_point(Vector3::new(3., 6., 0.)),
+        Vector3::new(1., 4., 0.)
+    );
+
+    let obb = Obb::new(
+        Vector3::new(1., 0., 0.),
+        Vector3::new(2., 1., 1.),
+        quarter_turn_z().to_matrix(),
+    );
+    assert!(obb.contains_point(Vector3::new(1., 1.5, 0.)));
+    assert!(!obb.contains_point(Vector3::new(2.5, 0., 0.)));
+    assert!(approx_eq(
+        obb.closest_point(Vector3::new(1., 5., 0.)),
+        Vector3::new(1., 2., 0.)
+    ));
+}
+
+#[test]
+fn ray_plane_intersection() {
+    let plane = Plane::new(Vector3::new(0., 1., 0.), 2.).unwrap();
+    let ray = Ray::new(Vector3::default(), Vector3::new(0., 1., 1.));
+
+    let hit = ray.intersect(&plane).unwrap();
+    assert!((hit.distance - 2. * 2f32.sqrt()).abs() < 1e-5);
+    assert!(approx_eq(hit.point, Vector3::new(0., 2., 2.)));
+    assert_eq!(hit.normal, plane.normal);
+
+    assert_eq!(
+        Ray::new(Vector3::default(), -plane.normal).intersect(&plane),
+        None
+    );
+    let parallel = Ray::new(Vector3::new(0., 2., 0.), Vector3::new(1., 0., 0.));
+    assert_eq!(parallel.intersect(&plane), None);
+}
+
+#[test]
+fn ray_sphere_intersection() {
+    let sphere = Sphere::new(Vector3::new(5., 0., 0.), 1.);
+    let x = Vector3::new(1., 0., 0.);
+
+    let hit = Ray::new(Vector3::default(), x).intersect(&sphere).unwrap();
+    assert_eq!(hit.distance, 4.);
+    assert_eq!(hit.normal, -x);
+
+    let inside = Ray::new(sphere.center, x).intersect(&sphere).unwrap();
+    assert_eq!(inside.point, Vector3::new(6., 0., 0.));
+    assert_eq!(inside.normal, x);
+
+    assert_eq!(Ray::new(Vector3::default(), -x).intersect(&sphere), None);
+    let grazing = Ray::new(Vector3::new(0., 1., 0.), x)
+        .intersect(&sphere)
+        .unwrap();
+    assert!(approx_eq(grazing.point, Vector3::new(5., 1., 0.)));
+    assert_eq!(
+        Ray::new(Vector3::new(0., 1.01, 0.), x).intersect(&sphere),
+        None
+    );
+}
+
+#[test]
+fn ray_aabb_intersection() {
+    let aabb = Aabb::new(Vector3::new(1., -1., -1.), Vector3::new(3., 1., 1.));
+
+    let hit = Ray::new(Vector3::new(0., 0.5, 0.), Vector3::new(1., 0., 0.))
+        .intersect(&aabb)
+        .unwrap();
+    assert_eq!(hit.distance, 1.);
+    assert_eq!(hit.normal, Vector3::new(-1., 0., 0.));
+
+    let diagonal = Ray::new(Vector3::new(2., 3., 0.), Vector3::new(0., -1., 0.))
+        .intersect(&aabb)
+        .unwrap();
+    assert_eq!(diagonal.point, Vector3::new(2., 1., 0.));
+    assert_eq!(diagonal.normal, Vector3::new(0., 1., 0.));
+
+    let inside = Ray::new(aabb.center(), Vector3::new(0., 0., -1.))
+        .intersect(&aabb)
+        .unwrap();
+    assert_eq!(inside.point, Vector3::new(2., 0., -1.));
+    assert_eq!(inside.normal, Vector3::new(0., 0., -1.));
+
+    // Parallel to the y and z slabs, outside the y slab.
+    let miss = Ray::new(Vector3::new(0., 2., 0.), Vector3::new(1., 0., 0.));
+    assert_eq!(miss.intersect(&aabb), None);
+    // Running along a face still counts as a hit.
+    let edge = Ray::new(Vector3::new(0., 1., 0.), Vector3::new(1., 0., 0.));
+    assert_eq!(edge.intersect(&aabb).unwrap().distance, 1.);
+}
+
+#[test]
+fn ray_triangle_intersection() {
+    let triangle = Triangle::new(
+        Vector3::new(0., 0., 0.),
+        Vector3::new(2., 0., 0.),
+        Vector3::new(0., 2., 0.),
+    );
+    let down = Vector3::new(0., 0., -1.);
+
+    let hit = Ray::new(Vector3::new(0.5, 1., 3.), down)
+        .intersect_triangle(triangle)
+        .unwrap();
+    assert_eq!(hit.hit.distance, 3.);
+    assert_eq!(hit.hit.normal, Vector3::new(0., 0., 1.));
+    assert!(approx_eq(hit.barycentric, Vector3::new(0.25, 0.25, 0.5)));
+
+    let back = Ray::new(Vector3::new(0.5, 0.5, -1.), -down);
+    assert_eq!(back.intersect(&triangle).unwrap().distance, 1.);
+
+    assert_eq!(
+        Ray::new(Vector3::new(1.5, 1.5, 3.), down).intersect(&triangle),
+        None
+    );
+    let parallel = Ray::new(Vector3::new(-1., 0.5, 0.), Vector3::new(1., 0., 0.));
+    assert_eq!(parallel.intersect(&triangle), None);
+}
+
+#[test]
+fn ray_capsule_intersection() {
+    let capsule = Capsule::new(Vector3::new(0., 0., 0.), Vector3::new(0., 4., 0.), 1.);
+    let x = Vector3::new(1., 0., 0.);
+
+    let side = Ray::new(Vector3::new(-5., 2., 0.), x)
+        .intersect(&capsule)
+        .unwrap();
+    assert!(approx_eq(side.point, Vector3::new(-1., 2., 0.)));
+    assert!(approx_eq(side.normal, -x));
+
+    let cap = Ray::new(Vector3::new(0., 10., 0.), Vector3::new(0., -1., 0.))
+        .intersect(&capsule)
+        .unwrap();
+    assert!((cap.distance - 5.).abs() < 1e-5);
+    assert!(approx_eq(cap.normal, Vector3::new(0., 1., 0.)));
+
+    let inside = Ray::new(Vector3::new(0., 1., 0.), Vector3::new(0., -1., 0.))
+        .intersect(&capsule)
+        .unwrap();
+    assert!(approx_eq(inside.point, Vector3::new(0., -1., 0.)));
+
+    assert_eq!(
+        Ray::new(Vector3::new(-5., 2., 1.5), x).intersect(&capsule),
+        None
+    );
+}
+
+#[test]
+fn ray_cylinder_intersection() {
+    let cylinder = Cylinder::new(Vector3::new(0., 0., 0.), Vector3::new(0., 4., 0.), 1.);
+    let x = Vector3::new(1., 0., 0.);
+    let down = Vector3::new(0., -1., 0.);
+
+    let side = Ray::new(Vector3::new(-5., 2., 0.), x)
+        .intersect(&cylinder)
+        .unwrap();
+    assert!(approx_eq(side.point, Vector3::new(-1., 2., 0.)));
+    assert!(approx_eq(side.normal, -x));
+
+    let cap = Ray::new(Vector3::new(0.5, 10., 0.), down)
+        .intersect(&cylinder)
+        .unwrap();
+    assert!((cap.distance - 6.).abs() < 1e-5);
+    assert!(approx_eq(cap.normal, -down));
+
+    let inside = Ray::new(Vector3::new(0., 2., 0.), down)
+        .intersect(&cylinder)
+        .unwrap();
+    assert!(approx_eq(inside.point, Vector3::default()));
+    assert!(approx_eq(inside.normal, down));
+
+    // Past the caps and beyond the rim.
+    assert_eq!(
+        Ray::new(Vector3::new(-5., 5., 0.), x).intersect(&cylinder),
+        None
+    );
+    assert_eq!(
+        Ray::new(Vector3::new(1.5, 10., 0.), down).intersect(&cylinder),
+        None
+    );
+}
+
+#[test]
+fn ray_obb_intersection() {
+    let obb = Obb::new(
+        Vector3::new(0., 0., 0.),
+        Vector3::new(2., 1., 1.),
+        quarter_turn_z().to_matrix(),
+    );
+
+    let hit = Ray::new(Vector3::new(0., 5., 0.), Vector3::new(0., -1., 0.))
+        .intersect(&obb)
+        .unwrap();
+    assert!((hit.distance - 3.).abs() < 1e-5);
+    assert!(approx_eq(hit.normal, Vector3::new(0., 1., 0.)));
+
+    assert_eq!(
+        Ray::new(Vector3::new(1.5, 5., 0.), Vector3::new(0., -1., 0.)).intersect(&obb),
+        None
+    );
+}
+
+fn unit_box() -> Aabb {
+    Aabb::new(Vector3::new(-1., -1., -1.), Vector3::new(1., 1., 1.))
+}
+
+#[test]
+fn sphere_sphere_overlap() {
+    let a = Sphere::new(Vector3::default(), 1.);
+    let b = Sphere::new(Vector3::new(1.5, 0., 0.), 1.);
+
+    let contact = a.contact(&b).unwrap();
+    assert_eq!(contact.normal, Vector3::new(1., 0., 0.));
+    assert_eq!(contact.depth, 0.5);
+    assert_eq!(b.contact(&a).unwrap().normal, Vector3::new(-1., 0., 0.));
+    assert!(!a.overlaps(&Sphere::new(Vector3::new(2.1, 0., 0.), 1.)));
+}
+
+#[test]
+fn sphere_aabb_overlap() {
+    let aabb = unit_box();
+
+    let sphere = Sphere::new(Vector3::new(0., 1.5, 0.), 1.);
+    let contact = sphere.contact(&aabb).unwrap();
+    assert_eq!(contact.normal, Vector3::new(0., -1., 0.));
+    assert_eq!(contact.depth, 0.5);
+    assert_eq!(
+        aabb.contact(&sphere).unwrap().normal,
+        Vector3::new(0., 1., 0.)
+    );
+
+    let inside = Sphere::new(Vector3::new(0.75, 0., 0.), 0.5);
+    let contact = inside.contact(&aabb).unwrap();
+    assert_eq!(contact.normal, Vector3::new(-1., 0., 0.));
+    assert_eq!(contact.depth, 0.75);
+
+    assert!(!Sphere::new(Vector3::new(2., 2., 0.), 1.).overlaps(&aabb));
+}
+
+#[test]
+fn aabb_aabb_overlap() {
+    let a = unit_box();
+    let b = a.translate(Vector3::new(0.5, 1.75, 0.));
+
+    let contact = a.contact(&b).unwrap();
+    assert_eq!(contact.normal, Vector3::new(0., 1., 0.));
+    assert_eq!(contact.depth, 0.25);
+    assert!(!a.overlaps(&a.translate(Vector3::new(0., 0., 2.5))));
+    assert!(a.overlaps(&a.translate(Vector3::new(0., 0., 2.))));
+}
+
+#[test]
+fn aabb_triangle_overlap() {
+    let aabb = unit_box();
+
+    let crossing = Triangle::new(
+        Vector3::new(0., 0., 0.5),
+        Vector3::new(5., 0., 0.5),
+        Vector3::new(0., 5., 0.5),
+    );
+    let contact = aabb.contact(&crossing).unwrap();
+    assert!(approx_eq(contact.normal, Vector3::new(0., 0., 1.)));
+    assert!((contact.depth - 0.5).abs() < 1e-5);
+    assert_eq!(crossing.contact(&aabb).unwrap().normal, -contact.normal);
+
+    // Separated only by the diagonal plane through the box edge.
+    let diagonal = Triangle::new(
+        Vector3::new(2.5, 0., -5.),
+        Vector3::new(0., 2.5, -5.),
+        Vector3::new(1.25, 1.25, 5.),
+    );
+    assert!(!aabb.overlaps(&diagonal));
+    assert!(aabb.overlaps(&diagonal.translate(Vector3::new(-0.6, -0.6, 0.))));
+}
+
+#[test]
+fn obb_obb_overlap() {
+    let a = Obb::from(unit_box());
+    let turn = Quaternion::from_axis_angle(
+        Vector3::new(0., 0., 1.),
+        Radians(std::f32::consts::FRAC_PI_4),
+    );
+    let diamond = Obb::new(
+        Vector3::new(2.2, 0., 0.),
+        Vector3::new(1., 1., 1.),
+        turn.to_matrix(),
+    );
+
+    let contact = a.contact(&diamond).unwrap();
+    assert!(approx_eq(contact.normal, Vector3::new(1., 0., 0.)));
+    assert!((contact.depth - (2f32.sqrt() - 1.2)).abs() < 1e-5);
+    assert!(!a.overlaps(&diamond.translate(Vector3::new(0.3, 0., 0.))));
+
+    // Edge-edge separation that no face axis detects.
+    let x_edge = Obb::new(
+        Vector3::new(0., 0., 0.),
+        Vector3::new(3., 0.1, 0.1),
+        Quaternion::from_axis_angle(Vector3::new(1., 0., 0.), Radians(0.7)).to_matrix(),
+    );
+    let y_edge = Obb::new(
+        Vector3::new(0., 0., 0.5),
+        Vector3::new(0.1, 3., 0.1),
+        Quaternion::from_axis_angle(Vector3::new(0., 1., 0.), Radians(0.7)).to_matrix(),
+    );
+    assert!(!x_edge.overlaps(&y_edge));
+}
+
+#[test]
+fn sphere_triangle_overlap() {
+    let triangle = Triangle::new(
+        Vector3::new(0., 0., 0.),
+        Vector3::new(2., 0., 0.),
+        Vector3::new(0., 2., 0.),
+    );
+
+    let above = Sphere::new(Vector3::new(0.5, 0.5, 0.75), 1.);
+    let contact = above.contact(&triangle).unwrap();
+    assert_eq!(contact.normal, Vector3::new(0., 0., -1.));
+    assert_eq!(contact.depth, 0.25);
+
+    let centered = Sphere::new(Vector3::new(0.5, 0.5, 0.), 1.);
+    assert_eq!(centered.contact(&triangle).unwrap().depth, 1.);
+    assert!(!Sphere::new(Vector3::new(-1., -1., 0.), 1.).overlaps(&triangle));
+}
+
+#[test]
+fn plane_classification() {
+    let plane = Plane::new(Vector3::new(0., 1., 0.), 1.).unwrap();
+
+    assert_eq!(
+        plane.classify(&Sphere::new(Vector3::new(0., 3., 0.), 1.)),
+        Side::Front
+    );
+    assert_eq!(plane.classify(&unit_box()), Side::Intersecting);
+    assert_eq!(
+        plane.classify(&unit_box().translate(Vector3::new(0., -0.5, 0.))),
+        Side::Back
+    );
+    let capsule = Capsule::new(Vector3::new(0., 3., 0.), Vector3::new(5., 1.5, 0.), 1.);
+    assert_eq!(plane.classify(&capsule), Side::Intersecting);
+    let cylinder = Cylinder::new(Vector3::new(0., 3., 0.), Vector3::new(0., 5., 0.), 10.);
+    assert_eq!(plane.classify(&cylinder), Side::Front);
+
+    let contact = plane.contact(&unit_box()).unwrap();
+    assert_eq!(contact.normal, plane.normal);
+    assert_eq!(contact.depth, 2.);
+    assert_eq!(
+        plane.contact(&Sphere::new(Vector3::new(0., 2.5, 0.), 1.)),
+        None
+    );
+}
+
+#[test]
+fn support_points() {
+    let x = Vector3::new(1., 0., 0.);
+    let direction = Vector3::new(1., 1., 0.);
+
+    assert_eq!(unit_box().support(direction), Vector3::new(1., 1., 1.));
+    assert!(approx_eq(
+        Sphere::new(x, 2.).support(direction),
+        x + direction.normalize() * 2.
+    ));
+    let cylinder = Cylinder::new(Vector3::default(), Vector3::new(0., 2., 0.), 1.);
+    assert!(approx_eq(
+        cylinder.support(direction),
+        Vector3::new(1., 2., 0.)
+    ));
+    let points = vec![x, -x, Vector3::new(0., 3., 0.)];
+    assert_eq!(points.support(direction), Vector3::new(0., 3., 0.));
+
+    let moved = Transformed::new(unit_box(), quarter_turn_z(), x * 5.);
+    assert!((moved.support(x).x - 6.).abs() < 1e-5);
+}
+
+#[test]
+fn gjk_separation() {
+    let a = Sphere::new(Vector3::default(), 1.);
+    let b = Sphere::new(Vector3::new(4., 0., 0.), 1.);
+
+    let separation = a.separation(&b).unwrap();
+    assert!((separation.distance - 2.).abs() < 1e-3);
+    assert!((separation.point_a - Vector3::new(1., 0., 0.)).magnitude() < 1e-2);
+    assert!((separation.point_b - Vector3::new(3., 0., 0.)).magnitude() < 1e-2);
+
+    let aabb = unit_box();
+    let segment = LineSegment::new(Vector3::new(-3., 3., 0.), Vector3::new(3., 3., 0.));
+    let separation = aabb.separation(&segment).unwrap();
+    assert!((separation.distance - 2.).abs() < 1e-5);
+    assert!((separation.point_a.y - 1.).abs() < 1e-5);
+
+    // A cube turned 45° about z reaches √2 along x.
+    let diamond = Transformed::new(
+        unit_box(),
+        Quaternion::from_axis_angle(
+            Vector3::new(0., 0., 1.),
+            Radians(std::f32::consts::FRAC_PI_4),
+        ),
+        Vector3::new(5., 0., 0.),
+    );
+    let separation = aabb.separation(&diamond).unwrap();
+    assert!((separation.distance - (4. - 2f32.sqrt())).abs() < 1e-4);
+    assert!(!aabb.intersects(&diamond));
+    assert!(aabb.intersects(&Sphere::new(Vector3::new(2.2, 0.5, 0.), 1.5)));
+}
+
+#[test]
+fn penetration_of_touching_and_flat_pairs() {
+    let a = unit_box();
+    let touching = a.translate(Vector3::new(2., 0., 0.));
+    if let Some(contact) = a.penetration(&touching) {
+        assert!(contact.depth < 1e-4);
+        assert!((contact.normal.magnitude() - 1.).abs() < 1e-5);
+    }
+
+    // Coincident shapes whose Minkowski difference has no volume.
+    let triangle = unit_triangle();
+    let point = Sphere::new(Vector3::new(0.5, 0.5, 0.), 0.);
+    let segment = LineSegment::new(Vector3::new(-1., 0., 0.), Vector3::new(1., 0., 0.));
+    let crossing = LineSegment::new(Vector3::new(0., -1., 0.), Vector3::new(0., 1., 0.));
+    for contact in [
+        triangle.penetration(&triangle),
+        point.penetration(&point),
+        segment.penetration(&crossing),
+        segment.penetration(&segment),
+    ] {
+        let contact = contact.unwrap();
+        assert!(contact.depth < 1e-4);
+        assert!((contact.normal.magnitude() - 1.).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn epa_penetration() {
+    let a = unit_box();
+    let b = a.translate(Vector3::new(1.5, 0.25, 0.));
+
+    let contact = a.penetration(&b).unwrap();
+    assert!(approx_eq(contact.normal, Vector3::new(1., 0., 0.)));
+    assert!((contact.depth - 0.5).abs() < 1e-4);
+    assert_eq!(a.penetration(&a.translate(Vector3::new(3., 0., 0.))), None);
+
+    let sphere = Sphere::new(Vector3::new(0., 0., 2.5), 2.);
+    let contact = a.penetration(&sphere).unwrap();
+    assert!((contact.normal - Vector3::new(0., 0., 1.)).magnitude() < 1e-2);
+    assert!((contact.depth - 0.5).abs() < 1e-2);
+
+    let hull = vec![
+        Vector3::new(0., 0., 0.),
+        Vector3::new(2., 0., 0.),
+        Vector3::new(0., 2., 0.),
+        Vector3::new(0., 0., 2.),
+    ];
+    let capsule = Capsule::new(Vector3::new(-1., 0.5, 0.5), Vector3::new(-1., 0.5, 5.), 1.2);
+    let contact = hull.penetration(&capsule).unwrap();
+    assert!((contact.normal - Vector3::new(-1., 0., 0.)).magnitude() < 1e-3);
+    assert!((contact.depth - 0.2).abs() < 1e-3);
+}
+
+#[test]
+fn epa_flat_overlap() {
+    let a = Triangle::new(
+        Vector3::new(0., 0., 0.),
+        Vector3::new(2., 0., 0.),
+        Vector3::new(0., 2., 0.),
+    );
+    let b = a.translate(Vector3::new(0.5, 0.5, 0.));
+
+    let contact = a.penetration(&b).unwrap();
+    assert_eq!(contact.depth, 0.);
+    assert!((contact.normal.z.abs() - 1.).abs() < 1e-5);
+}
+
+#[test]
+fn gjk_agrees_with_separating_axis() {
+    let turn = Quaternion::from_axis_angle(Vector3::new(1., 2., 3.).normalize(), Radians(0.6));
+    let a = Obb::from(unit_box());
+    for i in 0..9 {
+        for j in 0..9 {
+            let center = Vector3::new(i as f32 * 0.5 - 2., j as f32 * 0.5 - 2., 1.1);
+            let b = Obb::new(center, Vector3::new(1., 0.5, 0.25), turn.to_matrix());
+            let sat = a.overlaps(&b);
+            assert_eq!(a.intersects(&b), sat, "center {center}");
+            if let Some(contact) = a.penetration(&b) {
+                let expected = a.contact(&b).unwrap();
+                assert!(
+                    (contact.depth - expected.depth).abs() < 1e-3,
+                    "center {center}"
+                );
+            }
+        }
+    }
+}
+
+fn sphere_grid() -> Vec<Sphere> {
+    (0..64)
+        .map(|i| {
+            let (x, y, z) = (i % 4, (i / 4) % 4, i / 16);
+            let center = Vector3::new(x as f32 * 3., y as f32 * 3., z as f32 * 3.);
+            Sphere::new(center, 0.5 + (i % 3) as f32 * 0.25)
+        })
+        .collect()
+}
+
+fn sphere_bounds(spheres: &[Sphere]) -> Vec<Aabb> {
+    spheres
+        .iter()
+        .map(|s| Aabb::from_center_half_extents(s.center, Vector3::new(1., 1., 1.) * s.radius))
+        .collect()
+}
+
+#[test]
+fn bvh_ray_cast() {
+    let spheres = sphere_grid();
+    let bvh = Bvh::build(&sphere_bounds(&spheres));
+    assert_eq!(bvh.len(), 64);
+
+    for (origin, direction) in [
+        (Vector3::new(-5., 3., 6.), Vector3::new(1., 0., 0.)),
+        (Vector3::new(4.5, -5., 4.5), Vector3::new(0.1, 1., 0.2)),
+        (Vector3::new(20., 20., 20.), Vector3::new(-1., -1., -1.)),
+        (Vector3::new(3., 3., 3.), Vector3::new(0., 0., 1.)),
+    ] {
+        let ray = Ray::new(origin, direction);
+        let expected = spheres
+            .iter()
+            .enumerate()
+            .filter_map(|(i, s)| ray.intersect(s).map(|h| (i, h)))
+            .min_by(|a, b| a.1.distance.total_cmp(&b.1.distance));
+        let closest = bvh.cast_ray(ray, f32::INFINITY, |i| ray.intersect(&spheres[i]));
+        assert_eq!(closest, expected);
+
+        let any = bvh.cast_ray_any(ray, f32::INFINITY, |i| ray.intersect(&spheres[i]));
+        assert_eq!(any.is_some(), expected.is_some());
+    }
+
+    let ray = Ray::new(Vector3::new(-5., 0., 0.), Vector3::new(1., 0., 0.));
+    assert_eq!(
+        bvh.cast_ray_any(ray, 4., |i| ray.intersect(&spheres[i])),
+        None
+    );
+    assert!(bvh
+        .cast_ray_any(ray, 5., |i| ray.intersect(&spheres[i]))
+        .is_some());
+    let miss = Ray::new(Vector3::new(-5., 1.5, 1.5), Vector3::new(1., 0., 0.));
+    assert_eq!(
+        bvh.cast_ray(miss, f32::INFINITY, |i| miss.intersect(&spheres[i])),
+        None
+    );
+}
+
+#[test]
+fn bvh_region_queries() {
+    let spheres = sphere_grid();
+    let bounds = sphere_bounds(&spheres);
+    let bvh = Bvh::build(&bounds);
+
+    let region = Aabb::new(Vector3::new(2., 2., 2.), Vector3::new(6.5, 4., 9.));
+    let mut found = bvh.query_aabb(region);
+    found.sort();
+    let expected: Vec<usize> = (0..bounds.len())
+        .filter(|&i| bounds[i].overlaps(&region))
+        .collect();
+    assert_eq!(found, expected);
+    assert!(!found.is_empty());
+
+    let sphere = Sphere::new(Vector3::new(4.5, 4.5, 4.5), 2.);
+    let mut found = bvh.query_sphere(sphere);
+    found.sort();
+    let expected: Vec<usize> = (0..bounds.len())
+        .filter(|&i| sphere.overlaps(&bounds[i]))
+        .collect();
+    assert_eq!(found, expected);
+}
+
+#[test]
+fn bvh_nearest() {
+    let spheres = sphere_grid();
+    let bvh = Bvh::build(&sphere_bounds(&spheres));
+
+    for p in [
+        Vector3::new(4., 5., 1.),
+        Vector3::new(-10., 0., 30.),
+        Vector3::new(4.5, 4.5, 4.5),
+    ] {
+        let (index, point, distance) = bvh.nearest(p, |i| spheres[i].closest_point(p)).unwrap();
+        let expected = spheres
+            .iter()
+            .map(|s| s.distance_to_point(p))
+            .fold(f32::INFINITY, f32::min);
+        assert!((distance - expected).abs() < 1e-5);
+        assert!((spheres[index].distance_to_point(p) - expected).abs() < 1e-5);
+        assert!(((point - p).magnitude() - distance).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn bvh_skewed_input_matches_brute_force() {
+    // Doubling offsets leave most centroids in the first SAH bin at every
+    // level, which drives the build past its SAH depth into median splits.
+    let points: Vec<Vector3> = (-120..120)
+        .map(|i| Vector3::new(2f32.powi(i), 0., 0.))
+        .collect();
+    let bounds: Vec<Aabb> = points.iter().map(|&p| Aabb::new(p, p)).collect();
+    let bvh = Bvh::build(&bounds);
+
+    let query = Aabb::new(Vector3::new(0., -1., -1.), Vector3::new(1., 1., 1.));
+    let mut found = bvh.query_aabb(query);
+    found.sort();
+    let expected: Vec<usize> = (0..points.len()).filter(|&i| points[i].x <= 1.).collect();
+    assert_eq!(found, expected);
+
+    let p = Vector3::new(3e6, 0., 0.);
+    let (index, _, _) = bvh.nearest(p, |i| points[i]).unwrap();
+    assert_eq!(points[index], Vector3::new(2f32.powi(21), 0., 0.));
+}
+
+#[test]
+fn bvh_refit() {
+    let mut spheres = sphere_grid();
+    let mut bvh = Bvh::build(&sphere_bounds(&spheres));
+
+    let offset = Vector3::new(0., 0., 100.);
+    spheres[0] = spheres[0].translate(offset);
+    bvh.refit(&sphere_bounds(&spheres)).unwrap();
+    assert_eq!(
+        bvh.refit(&sphere_bounds(&spheres[1..])),
+        Err(MathError::LengthMismatch)
+    );
+
+    assert!(bvh.bounds().unwrap().contains_point(spheres[0].center));
+    assert_eq!(bvh.query_aabb(Aabb::new(offset, offset)), vec![0]);
+    assert!(!bvh
+        .query_aabb(Aabb::new(Vector3::default(), Vector3::default()))
+        .contains(&0));
+
+    let empty = Bvh::build(&[]);
+    assert!(empty.is_empty() && empty.bounds().is_none());
+    let ray = Ray::new(Vector3::default(), offset);
+    assert_eq!(empty.cast_ray(ray, f32::INFINITY, |_| None), None);
+    assert_eq!(empty.nearest(offset, |_| offset), None);
+}
+
+fn assert_valid_hull(hull: &ConvexHull, points: &[Vector3]) {
+    // Closed two-manifold: every directed edge is matched by its reverse.
+    let mut edges = std::collections::HashSet::new();
+    for t in &hull.triangles {
+        for (a, b) in [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])] {
+            assert!(edges.insert((a, b)), "edge used twice");
+        }
+    }
+    assert!(edges.iter().all(|(a, b)| edges.contains(&(*b, *a))));
+    assert_eq!(
+        hull.vertices.len() + hull.triangles.len() - edges.len() / 2,
+        2
+    );
+
+    for (t, normal) in hull.triangles.iter().zip(&hull.normals) {
+        let [a, b, c] = t.map(|i| hull.vertices[i]);
+        assert!(approx_eq((b - a).cross(c - a).normalize(), *normal));
+        assert!(points.iter().all(|p| normal.dot(*p - a) <= 1e-4));
+    }
+}
+
+#[test]
+fn convex_hull_of_cube_with_redundant_points() {
+    let mut points = Vec::new();
+    for x in 0..4 {
+        for y in 0..4 {
+            for z in 0..4 {
+                points.push(Vector3::new(x as f32, y as f32, z as f32));
+            }
+        }
+    }
+    points.extend_from_slice(&points.clone()[..10]);
+
+    let hull = ConvexHull::from_points(&points).unwrap();
+    assert_valid_hull(&hull, &points);
+    assert_eq!(hull.vertices.len(), 8);
+    assert_eq!(hull.triangles.len(), 12);
+    assert_eq!(
+        hull.support(Vector3::new(1., 1., 1.)),
+        Vector3::new(3., 3., 3.)
+    );
+}
+
+#[test]
+fn convex_hull_of_sphere_points() {
+    // Fibonacci sphere plus interior points.
+    let golden = std::f32::consts::PI * (3. - 5f32.sqrt());
+    let mut points: Vec<Vector3> = (0..200)
+        .map(|i| {
+            let y = 1. - 2. * (i as f32 + 0.5) / 200.;
+            let r = (1. - y * y).sqrt();
+            let phi = golden * i as f32;
+            Vector3::new(r * phi.cos(), y, r * phi.sin()) * 10. + Vector3::new(100., 0., 0.)
+        })
+        .collect();
+    points.extend((0..50).map(|i| Vector3::new(100. + (i % 5) as f32, (i % 7) as f32, 0.)));
+
+    let hull = ConvexHull::from_points(&points).unwrap();
+    assert_valid_hull(&hull, &points);
+    assert_eq!(hull.vertices.len(), 200);
+}
+
+#[test]
+fn convex_hull_degenerate_input() {
+    let coplanar: Vec<Vector3> = (0..10)
+        .map(|i| Vector3::new(i as f32, (i * i) as f32, 0.))
+        .collect();
+
+    assert_eq!(
+        ConvexHull::from_points(&coplanar),
+        Err(MathError::Degenerate)
+    );
+    assert_eq!(ConvexHull::from_points(&[]), Err(MathError::Degenerate));
+    assert_eq!(
+        ConvexHull::from_points(&[Vector3::new(1., 2., 3.); 5]),
+        Err(MathError::Degenerate)
+    );
+}
+
+/// Projection for a camera looking along +Z, mapping `near..far` to the
+/// given clip depth range.
+fn projection(fov_y: f32, aspect: f32, near: f32, far: f32, depth: DepthRange) -> [[f32; 4]; 4] {
+    let f = 1. / (fov_y * 0.5).tan();
+    let (a, b) = match depth {
+        DepthRange::NegativeOneToOne => {
+            ((far + near) / (far - near), -2. * far * near / (far - near))
+        }
+        DepthRange::ZeroToOne => (far / (far - near), -far * near / (far - near)),
+    };
+    [
+        [f / aspect, 0., 0., 0.],
+        [0., f, 0., 0.],
+        [0., 0., a, b],
+        [0., 0., 1., 0.],
+    ]
+}
+
+fn test_frustum() -> Frustum {
+    Frustum::perspective(
+        Vector3::default(),
+        Quaternion::identity(),
+        Radians(std::f32::consts::FRAC_PI_2),
+        2.,
+        1.,
+        10.,
+    )
+    .unwrap()
+}
+
+#[test]
+fn frustum_extraction_matches_camera() {
+    let camera = test_frustum();
+    for depth in [DepthRange::NegativeOneToOne, DepthRange::ZeroToOne] {
+        let m = projection(std::f32::consts::FRAC_PI_2, 2., 1., 10., depth);
+        let extracted = Frustum::from_view_projection(m, depth).unwrap();
+        for (a, b) in extracted.planes.iter().zip(camera.planes) {
+            assert!(approx_eq(a.normal, b.normal));
+            assert!((a.distance - b.distance).abs() < 1e-4);
+        }
+    }
+
+    let corners = camera.corners().unwrap();
+    assert!(approx_eq(corners[0], Vector3::new(-2., -1., 1.)));
+    assert!(approx_eq(corners[7], Vector3::new(20., 10., 10.)));
+}
+
+#[test]
+fn frustum_culling() {
+    let turn = Quaternion::from_axis_angle(
+        Vector3::new(0., 1., 0.),
+        Radians(std::f32::consts::FRAC_PI_2),
+    );
+    // Looking along +X after the turn.
+    let frustum = Frustum::perspective(
+        Vector3::new(0., 5., 0.),
+        turn,
+        Radians(std::f32::consts::FRAC_PI_2),
+        1.,
+        1.,
+        10.,
+    )
+    .unwrap();
+
+    assert!(frustum.contains_point(Vector3::new(5., 5., 0.)));
+    assert!(!frustum.contains_point(Vector3::new(-5., 5., 0.)));
+    assert!(!frustum.contains_point(Vector3::new(11., 5., 0.)));
+
+    assert!(frustum.intersects_sphere(&Sphere::new(Vector3::new(0., 5., 0.), 1.5)));
+    assert!(!frustum.intersects_sphere(&Sphere::new(Vector3::new(-3., 5., 0.), 1.)));
+
+    let cube = |center: Vector3| Aabb::from_center_half_extents(center, Vector3::new(1., 1., 1.));
+    assert_eq!(
+        frustum.intersects_aabb(&cube(Vector3::new(5., 5., 0.))),
+        Containment::Inside
+    );
+    assert_eq!(
+        frustum.intersects_aabb(&cube(Vector3::new(10., 5., 0.))),
+        Containment::Intersecting
+    );
+    assert_eq!(
+        frustum.intersects_aabb(&cube(Vector3::new(5., 5., 8.))),
+        Containment::Outside
+    );
+}
+
+#[test]
+fn frustum_cascades() {
+    let splits = Frustum::cascade_splits(1., 10., 3, 0.5).unwrap();
+    assert_eq!(splits.len(), 4);
+    assert_eq!(splits[0], 0.);
+    assert!((splits[3] - 1.).abs() < 1e-6);
+    assert!(splits.windows(2).all(|w| w[0] < w[1]));
+    let uniform = Frustum::cascade_splits(1., 10., 3, 0.).unwrap();
+    assert!((uniform[1] - 1. / 3.).abs() < 1e-6);
+    for (near, far, count, lambda) in [
+        (1., 10., 0, 0.5),
+        (0., 10., 3, 0.5),
+        (-1., 10., 3, 0.5),
+        (5., 5., 3, 0.5),
+        (1., 10., 3, -0.1),
+        (1., 10., 3, 1.5),
+    ] {
+        assert_eq!(
+            Frustum::cascade_splits(near, far, count, lambda),
+            Err(MathError::OutOfRange)
+        );
+    }
+
+    let frustum = test_frustum();
+    let corners = frustum.corners().unwrap();
+    let cascades = frustum.cascades(&[0., 0.5, 1.]).unwrap();
+    assert_eq!(cascades.len(), 2);
+    let near = cascades[0].corners().unwrap();
+    let far = cascades[1].corners().unwrap();
+    assert!(approx_eq(near[0], corners[0]));
+    assert!(approx_eq(near[4], far[0]));
+    assert!(approx_eq(near[4], Vector3::new(-11., -5.5, 5.5)));
+    assert!(approx_eq(far[7], corners[7]));
+    assert_eq!(frustum.cascades(&[0.5, 0.5]), Err(MathError::Degenerate));
+}
+
+#[test]
+fn frustum_degenerate_inputs() {
+    // The left plane copies the bottom one, so they meet in no corner.
+    let mut parallel = test_frustum();
+    parallel.planes[0] = parallel.planes[2];
+    assert_eq!(parallel.corners(), Err(MathError::Degenerate));
+    assert_eq!(parallel.cascades(&[0., 1.]), Err(MathError::Degenerate));
+
+    let flat = Frustum::perspective(
+        Vector3::default(),
+        Quaternion::identity(),
+        Radians(0.),
+        1.,
+        1.,
+        10.,
+    );
+    assert_eq!(flat, Err(MathError::Degenerate));
+    assert_eq!(
+        Frustum::from_corners([Vector3::default(); 8]),
+        Err(MathError::Degenerate)
+    );
+}
+
+fn box_points(center: Vector3, half_extents: Vector3, rotation: Quaternion) -> Vec<Vector3> {
+    let mut points = Vec::new();
+    for i in 0..5 {
+        for j in 0..5 {
+            for k in 0..5 {
+                let unit =
+                    Vector3::new(i as f32, j as f32, k as f32) * 0.5 - Vector3::new(1., 1., 1.);
+                let local = Vector3::new(
+                    unit.x * half_extents.x,
+                    unit.y * half_extents.y,
+                    unit.z * half_extents.z,
+                );
+                points.push(rotation.rotate(local) + center);
+            }
+        }
+    }
+    points
+}
+
+#[test]
+fn aabb_from_points() {
+    let points = [
+        Vector3::new(1., -2., 0.),
+        Vector3::new(-1., 3., 2.),
+        Vector3::new(0., 0., -4.),
+    ];
+
+    let aabb = Aabb::from_points(&points).unwrap();
+    assert_eq!(aabb.min, Vector3::new(-1., -2., -4.));
+    assert_eq!(aabb.max, Vector3::new(1., 3., 2.));
+    assert_eq!(Aabb::from_points(&[]), None);
+}
+
+#[test]
+fn obb_from_points() {
+    let rotation = Quaternion::from_axis_angle(Vector3::new(1., 2., 3.).normalize(), Radians(0.8));
+    let center = Vector3::new(5., -2., 1.);
+    let points = box_points(center, Vector3::new(4., 2., 1.), rotation);
+
+    let obb = Obb::from_points(&points).unwrap();
+    assert!((obb.center - center).magnitude() < 1e-3);
+    assert!((obb.volume() - 64.).abs() < 1e-2);
+    assert!(points.iter().all(|p| obb.distance_to_point(*p) < 1e-4));
+    // The longest axis is recovered up to sign.
+    let longest = rotation.rotate(Vector3::new(1., 0., 0.));
+    assert!((obb.axis(0).dot(longest).abs() - 1.) < 1e-4);
+    assert!(obb.rotation().to_matrix().is_rotation(1e-4));
+
+    let from_quaternion = Obb::from_rotation(center, obb.half_extents, obb.rotation());
+    assert!(approx_eq(from_quaternion.axis(2), obb.axis(2)));
+    assert_eq!(Obb::from_points(&[]), None);
+}
+
+#[test]
+fn obb_refinement_does_not_grow() {
+    // PCA is misled by the uneven density of these points.
+    let mut points = box_points(
+        Vector3::default(),
+        Vector3::new(1., 1., 1.),
+        Quaternion::identity(),
+    );
+    points.extend((0..40).map(|i| Vector3::new(1., 1., 1.) * (i as f32 / 40.)));
+
+    let pca = Obb::from_points(&points).unwrap();
+    let refined = Obb::from_points_refined(&points, 8).unwrap();
+    assert!(pca.volume() > 9.);
+    assert!(refined.volume() < 8.1);
+    assert!(points.iter().all(|p| refined.distance_to_point(*p) < 1e-4));
+}
+
+#[test]
+fn minimal_bounding_sphere() {
+    let points = box_points(
+        Vector3::new(3., 0., 0.),
+        Vector3::new(1., 1., 1.),
+        Quaternion::identity(),
+    );
+    let sphere = Sphere::from_points(&points).unwrap();
+    assert!(approx_eq(sphere.center, Vector3::new(3., 0., 0.)));
+    assert!((sphere.radius - 3f32.sqrt()).abs() < 1e-4);
+
+    // Two far points fix the sphere; the rest lie inside it.
+    let points = [
+        Vector3::new(-5., 0., 0.),
+        Vector3::new(0., 1., 1.),
+        Vector3::new(5., 0., 0.),
+        Vector3::new(1., -2., 0.),
+    ];
+    let sphere = Sphere::from_points(&points).unwrap();
+    assert!(approx_eq(sphere.center, Vector3::default()));
+    assert!((sphere.radius - 5.).abs() < 1e-5);
+
+    // Equilateral triangle: the circumcircle, not the sphere of any pair.
+    let h = 3f32.sqrt();
+    let points = [
+        Vector3::new(-1., 0., 0.),
+        Vector3::new(1., 0., 0.),
+        Vector3::new(0., h, 0.),
+    ];
+    let sphere = Sphere::from_points(&points).unwrap();
+    assert!((sphere.radius - 2. / h).abs() < 1e-5);
+
+    assert_eq!(
+        Sphere::from_points(&[Vector3::new(1., 1., 1.); 3]),
+        Some(Sphere::new(Vector3::new(1., 1., 1.), 0.))
+    );
+    assert_eq!(Sphere::from_points(&[]), None);
+}
+
+fn unit_triangle() -> Triangle {
+    Triangle::new(
+        Vector3::new(0., 0., 0.),
+        Vector3::new(2., 0., 0.),
+        Vector3::new(0., 2., 0.),
+    )
+}
+
+#[test]
+fn segment_segment_closest_points() {
+    let a = LineSegment::new(Vector3::new(0., 0., 0.), Vector3::new(4., 0., 0.));
+
+    let crossing = LineSegment::new(Vector3::new(1., -1., 2.), Vector3::new(1., 1., 2.));
+    let closest = a.closest_points(&crossing);
+    assert_eq!(closest.point_a, Vector3::new(1., 0., 0.));
+    assert_eq!(closest.point_b, Vector3::new(1., 0., 2.));
+    assert_eq!(closest.distance_squared, 4.);
+
+    let beyond = LineSegment::new(Vector3::new(6., 1., 0.), Vector3::new(6., 3., 0.));
+    let closest = a.closest_points(&beyond);
+    assert_eq!(closest.point_a, Vector3::new(4., 0., 0.));
+    assert_eq!(closest.point_b, Vector3::new(6., 1., 0.));
+
+    let parallel = LineSegment::new(Vector3::new(2., 1., 0.), Vector3::new(8., 1., 0.));
+    assert_eq!(a.closest_points(&parallel).distance_squared, 1.);
+
+    let point = LineSegment::new(Vector3::new(2., 3., 0.), Vector3::new(2., 3., 0.));
+    let closest = a.closest_points(&point);
+    assert_eq!(closest.point_a, Vector3::new(2., 0., 0.));
+    assert_eq!(closest.flip().point_a, point.start);
+}
+
+#[test]
+fn ray_segment_closest_points() {
+    let segment = LineSegment::new(Vector3::new(5., -1., 1.), Vector3::new(5., 1., 1.));
+
+    let ray = Ray::new(Vector3::default(), Vector3::new(1., 0., 0.));
+    let closest = ray.closest_points(&segment);
+    assert_eq!(closest.point_a, Vector3::new(5., 0., 0.));
+    assert_eq!(closest.point_b, Vector3::new(5., 0., 1.));
+    assert_eq!(closest.distance_squared, 1.);
+
+    let away = Ray::new(Vector3::default(), Vector3::new(-1., 0., 0.));
+    let closest = segment.closest_points(&away);
+    assert_eq!(closest.point_b, Vector3::default());
+    assert_eq!(closest.distance_squared, 26.);
+}
+
+#[test]
+fn point_closest_points() {
+    let p = Vector3::new(0.5, 0.5, 3.);
+    let closest = p.closest_points(&unit_triangle());
+    assert_eq!(closest.point_a, p);
+    assert_eq!(closest.point_b, Vector3::new(0.5, 0.5, 0.));
+    assert_eq!(closest.distance_squared, 9.);
+
+    let obb = Obb::new(
+        Vector3::default(),
+        Vector3::new(2., 1., 1.),
+        quarter_turn_z().to_matrix(),
+    );
+    let closest = Vector3::new(3., 0., 0.).closest_points(&obb);
+    assert!(approx_eq(closest.point_b, Vector3::new(1., 0., 0.)));
+    assert!((closest.distance_squared - 4.).abs() < 1e-5);
+}
+
+#[test]
+fn segment_triangle_closest_points() {
+    let triangle = unit_triangle();
+
+    let piercing = LineSegment::new(Vector3::new(0.5, 0.5, -1.), Vector3::new(0.5, 0.5, 1.));
+    let closest = piercing.closest_points(&triangle);
+    assert_eq!(closest.distance_squared, 0.);
+    assert!(approx_eq(closest.point_a, Vector3::new(0.5, 0.5, 0.)));
+
+    let above = LineSegment::new(Vector3::new(0.5, 0.5, 2.), Vector3::new(0.5, 0.5, 1.));
+    let closest = triangle.closest_points(&above);
+    assert_eq!(closest.point_a, Vector3::new(0.5, 0.5, 0.));
+    assert_eq!(closest.point_b, Vector3::new(0.5, 0.5, 1.));
+
+    let beside = LineSegment::new(Vector3::new(2., 2., -1.), Vector3::new(2., 2., 1.));
+    let closest = beside.closest_points(&triangle);
+    assert!(approx_eq(closest.point_a, Vector3::new(2., 2., 0.)));
+    assert!(approx_eq(closest.point_b, Vector3::new(1., 1., 0.)));
+    assert!((closest.distance_squared - 2.).abs() < 1e-5);
+}
+
+#[test]
+fn triangle_triangle_closest_points() {
+    let a = unit_triangle();
+
+    let stacked = a.translate(Vector3::new(0., 0., 2.));
+    assert!((a.closest_points(&stacked).distance_squared - 4.).abs() < 1e-6);
+
+    let piercing = Triangle::new(
+        Vector3::new(0.5, 0.5, -1.),
+        Vector3::new(0.5, 0.5, 1.),
+        Vector3::new(5., 5., 0.),
+    );
+    assert_eq!(a.closest_points(&piercing).distance_squared, 0.);
+
+    let edge_to_edge = Triangle::new(
+        Vector3::new(2., 2., -1.),
+        Vector3::new(2., 2., 1.),
+        Vector3::new(4., 4., 0.),
+    );
+    let closest = a.closest_points(&edge_to_edge);
+    assert!(approx_eq(closest.point_a, Vector3::new(1., 1., 0.)));
+    assert!(approx_eq(closest.point_b, Vector3::new(2., 2., 0.)));
+    assert!((closest.distance_squared - 2.).abs() < 1e-5);
+}
+
+#[test]
+fn closest_points_agree_with_gjk() {
+    let mut state = 12345u32;
+    let mut next = move || {
+        state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+        (state >> 8) as f32 / (1u32 << 24) as f32 * 4. - 2.
+    };
+    let mut point = || Vector3::new(next(), next(), next());
+
+    for _ in 0..50 {
+        let a = Triangle::new(point(), point(), point());
+        let b = Triangle::new(point(), point(), point()).translate(Vector3::new(1., 0., 0.));
+        let closest = a.closest_points(&b);
+        let expected = a.separation(&b).map_or(0., |s| s.distance);
+        assert!((closest.distance_squared.sqrt() - expected).abs() < 1e-3);
+
+        let segment = LineSegment::new(point(), point());
+        let closest = segment.closest_points(&b);
+        let expected = segment.separation(&b).map_or(0., |s| s.distance);
+        assert!((closest.distance_squared.sqrt() - expected).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn triangle_area_and_normals() {
+    let triangle = unit_triangle();
+    assert!((triangle.area() - 2.).abs() < 1e-5);
+    assert_eq!(triangle.weighted_normal(), Vector3::new(0., 0., 4.));
+    assert_eq!(triangle.normal(), Vector3::new(0., 0., 1.));
+}
+
+#[test]
+fn triangle_barycentric_round_trips() {
+    let triangle = unit_triangle();
+    let weights = triangle.barycentric(Vector3::new(0.5, 0.5, 3.));
+    assert!(approx_eq(weights, Vector3::new(0.5, 0.25, 0.25)));
+
+    let point = Triangle::interpolate(weights, [triangle.a, triangle.b, triangle.c]);
+    assert!(approx_eq(point, Vector3::new(0.5, 0.5, 0.)));
+    let value = Triangle::interpolate(weights, [1., 2., 3.]);
+    assert!((value - 1.75).abs() < 1e-5);
+    let uv = Triangle::interpolate(
+        weights,
+        [
+            Vector2::new(0., 0.),
+            Vector2::new(1., 0.),
+            Vector2::new(0., 1.),
+        ],
+    );
+    assert!((uv - Vector2::new(0.25, 0.25)).magnitude() < 1e-5);
+
+    assert_eq!(triangle.barycentric(triangle.b), Vector3::new(0., 1., 0.));
+}
+
+#[test]
+fn triangle_contains_projection() {
+    let triangle = unit_triangle();
+    assert!(triangle.contains_projection(Vector3::new(0.5, 0.5, -4.)));
+    assert!(triangle.contains_projection(Vector3::new(1., 1., 0.)));
+    assert!(!triangle.contains_projection(Vector3::new(1.5, 1.5, 0.)));
+    assert!(!triangle.contains_projection(Vector3::new(-0.1, 0.5, 0.)));
+}
+
+#[test]
+fn triangle_centers() {
+    let triangle = unit_triangle();
+    let circumcenter = triangle.circumcenter();
+    assert!(approx_eq(circumcenter, Vector3::new(1., 1., 0.)));
+    for vertex in [triangle.a, triangle.b, triangle.c] {
+        assert!(((vertex - circumcenter).magnitude() - 2_f32.sqrt()).abs() < 1e-5);
+    }
+
+    // Inradius of a right triangle is (legs - hypotenuse) / 2.
+    let r = (4. - 8_f32.sqrt()) / 2.;
+    let incenter = triangle.incenter();
+    assert!(approx_eq(incenter, Vector3::new(r, r, 0.)));
+}
+
+#[test]
+fn triangle_tangent_basis_follows_uvs() {
+    let triangle = unit_triangle();
+    let uvs = [
+        Vector2::new(0., 0.),
+        Vector2::new(1., 0.),
+        Vector2::new(0., 1.),
+    ];
+    let (tangent, bitangent) = triangle.tangent_basis(uvs).unwrap();
+    assert_eq!(tangent, Vector3::new(2., 0., 0.));
+    assert_eq!(bitangent, Vector3::new(0., 2., 0.));
+
+    let flipped = [
+        Vector2::new(0., 0.),
+        Vector2::new(0., 1.),
+        Vector2::new(1., 0.),
+    ];
+    let (tangent, bitangent) = triangle.tangent_basis(flipped).unwrap();
+    assert_eq!(tangent, Vector3::new(0., 2., 0.));
+    assert_eq!(bitangent, Vector3::new(2., 0., 0.));
+
+    let collinear = [
+        Vector2::new(0., 0.),
+        Vector2::new(1., 1.),
+        Vector2::new(2., 2.),
+    ];
+    assert_eq!(
+        triangle.tangent_basis(collinear),
+        Err(MathError::Degenerate)
+    );
+}
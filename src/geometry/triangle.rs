@@ -0,0 +1,167 @@
+use std::ops::{Add, Mul};
+
+use super::{PointQuery, Transform, EPSILON};
+use crate::prelude::{MathError, Matrix3x3, Vector2, Vector3};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Triangle {
+    pub a: Vector3,
+    pub b: Vector3,
+    pub c: Vector3,
+}
+
+impl Triangle {
+    pub fn new(a: Vector3, b: Vector3, c: Vector3) -> Self {
+        Triangle { a, b, c }
+    }
+
+    /// `(b - a) × (c - a)`: normal to the counter-clockwise side with a
+    /// length of twice the area, for area-weighted vertex normals.
+    pub fn weighted_normal(self) -> Vector3 {
+        (self.b - self.a).cross(self.c - self.a)
+    }
+
+    pub fn normal(self) -> Vector3 {
+        self.weighted_normal().normalize()
+    }
+
+    pub fn area(self) -> f32 {
+        self.weighted_normal().magnitude() * 0.5
+    }
+
+    /// Weights `(u, v, w)` with `u * a + v * b + w * c` equal to the
+    /// projection of `p` onto the triangle's plane. The weights sum to one and
+    /// are all non-negative inside the triangle. Degenerate triangles give
+    /// non-finite weights.
+    pub fn barycentric(self, p: Vector3) -> Vector3 {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let ap = p - self.a;
+        let d00 = ab.dot(ab);
+        let d01 = ab.dot(ac);
+        let d11 = ac.dot(ac);
+        let d20 = ap.dot(ab);
+        let d21 = ap.dot(ac);
+        let denom = d00 * d11 - d01 * d01;
+        let v = (d11 * d20 - d01 * d21) / denom;
+        let w = (d00 * d21 - d01 * d20) / denom;
+        Vector3::new(1. - v - w, v, w)
+    }
+
+    /// Blends per-vertex attributes with barycentric `weights`.
+    pub fn interpolate<T>(weights: Vector3, attributes: [T; 3]) -> T
+    where
+        T: Add<Output = T> + Mul<f32, Output = T> + Copy,
+    {
+        let [a, b, c] = attributes;
+        a * weights.x + b * weights.y + c * weights.z
+    }
+
+    /// Whether `p`, projected along the normal onto the triangle's plane,
+    /// lands inside the triangle or on its boundary.
+    pub fn contains_projection(self, p: Vector3) -> bool {
+        let weights = self.barycentric(p);
+        weights.x >= 0. && weights.y >= 0. && weights.z >= 0.
+    }
+
+    /// Center of the circle through the three vertices. Degenerate triangles
+    /// give a non-finite point.
+    pub fn circumcenter(self) -> Vector3 {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let normal = ab.cross(ac);
+        let offset = (normal.cross(ab) * ac.magnitude_squared()
+            + ac.cross(normal) * ab.magnitude_squared())
+            / (2. * normal.magnitude_squared());
+        self.a + offset
+    }
+
+    /// Center of the inscribed circle: the vertices weighted by the lengths
+    /// of their opposite sides.
+    pub fn incenter(self) -> Vector3 {
+        let la = (self.c - self.b).magnitude();
+        let lb = (self.a - self.c).magnitude();
+        let lc = (self.b - self.a).magnitude();
+        (self.a * la + self.b * lb + self.c * lc) / (la + lb + lc)
+    }
+
+    /// Tangent and bitangent, the directions of increasing `u` and `v` given
+    /// texture coordinates for `a`, `b` and `c`. Neither is normalized, so
+    /// they can be summed per vertex before orthonormalizing against the
+    /// normal. Fails when the texture coordinates are collinear.
+    pub fn tangent_basis(self, uvs: [Vector2; 3]) -> Result<(Vector3, Vector3), MathError> {
+        let e1 = self.b - self.a;
+        let e2 = self.c - self.a;
+        let d1 = uvs[1] - uvs[0];
+        let d2 = uvs[2] - uvs[0];
+        let det = d1.cross(d2);
+        if det.abs() <= f32::EPSILON * d1.magnitude_squared().max(d2.magnitude_squared()) {
+            return Err(MathError::Degenerate);
+        }
+        let tangent = (e1 * d2.y - e2 * d1.y) / det;
+        let bitangent = (e2 * d1.x - e1 * d2.x) / det;
+        Ok((tangent, bitangent))
+    }
+}
+
+impl PointQuery for Triangle {
+    fn contains_point(&self, p: Vector3) -> bool {
+        self.distance_to_point(p) <= EPSILON
+    }
+
+    /// Voronoi region walk from Ericson, Real-Time Collision Detection, 5.1.5.
+    fn closest_point(&self, p: Vector3) -> Vector3 {
+        let (a, b, c) = (self.a, self.b, self.c);
+        let ab = b - a;
+        let ac = c - a;
+
+        let ap = p - a;
+        let d1 = ab.dot(ap);
+        let d2 = ac.dot(ap);
+        if d1 <= 0. && d2 <= 0. {
+            return a;
+        }
+
+        let bp = p - b;
+        let d3 = ab.dot(bp);
+        let d4 = ac.dot(bp);
+        if d3 >= 0. && d4 <= d3 {
+            return b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= 0. && d1 >= 0. && d3 <= 0. {
+            return a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp = p - c;
+        let d5 = ab.dot(cp);
+        let d6 = ac.dot(cp);
+        if d6 >= 0. && d5 <= d6 {
+            return c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= 0. && d2 >= 0. && d6 <= 0. {
+            return a + ac * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= 0. && (d4 - d3) >= 0. && (d5 - d6) >= 0. {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        let denom = 1. / (va + vb + vc);
+        a + ab * (vb * denom) + ac * (vc * denom)
+    }
+}
+
+impl Transform for Triangle {
+    fn transform(self, m: Matrix3x3) -> Self {
+        Triangle::new(m * self.a, m * self.b, m * self.c)
+    }
+
+    fn translate(self, offset: Vector3) -> Self {
+        Triangle::new(self.a + offset, self.b + offset, self.c + offset)
+    }
+}
@@ -1,9 +1,35 @@
+mod angle;
+mod coordinates;
+mod dual_quaternion;
+mod error;
+mod geometry;
+mod lie;
 mod matrices;
 mod quaternion;
+#[cfg(feature = "rand")]
+mod random;
 mod vectors;
 
 pub mod prelude {
+    pub use crate::angle::{Degrees, Radians};
+    pub use crate::coordinates::{
+        AngleConvention, Axis, CoordinateSystem, Cylindrical, Handedness, Polar, Spherical, UpAxis,
+    };
+    pub use crate::dual_quaternion::DualQuaternion;
+    pub use crate::error::MathError;
+    pub use crate::geometry::{
+        Aabb, Bvh, Capsule, ClosestPoints, Contact, Containment, ConvexHull, Cylinder, DepthRange,
+        Frustum, Interval, LineSegment, Obb, Overlap, Plane, PointQuery, Proximity, Ray, RayCast,
+        RayHit, Separation, Side, Sphere, SupportMap, Transform, Transformed, Triangle,
+        TriangleHit,
+    };
+    pub use crate::lie::{Matrix6, Twist, SE3, SO3};
+    pub use crate::matrices::decomposition::{Cholesky, Lu, Qr, SquareMatrix};
     pub use crate::matrices::Matrix3x3;
     pub use crate::quaternion::Quaternion;
-    pub use crate::vectors::Vector3;
+    #[cfg(feature = "rand")]
+    pub use crate::random::{
+        CosineHemisphere, Hemisphere, UniformRotation, UnitBall, UnitCube, UnitSphere,
+    };
+    pub use crate::vectors::{Vector2, Vector3};
 }
@@ -0,0 +1,322 @@
+use std::ops::Mul;
+
+use crate::matrices::{product, scaled};
+use crate::prelude::{Matrix3x3, Quaternion, Vector3};
+
+const SMALL_ANGLE: f32 = 1e-3;
+
+/// Rotation group, stored as a unit quaternion. Tangent vectors are rotation
+/// vectors `axis * angle`.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SO3 {
+    rotation: Quaternion,
+}
+
+/// Rigid transform group. Tangent vectors are [`Twist`]s.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SE3 {
+    pub rotation: SO3,
+    pub translation: Vector3,
+}
+
+/// Tangent vector of [`SE3`], ordered `(linear, angular)` in 6x6 Jacobians.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Twist {
+    pub linear: Vector3,
+    pub angular: Vector3,
+}
+
+/// 6x6 matrix stored as 3x3 blocks, `[[top_left, top_right], [bottom_left, bottom_right]]`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Matrix6 {
+    pub blocks: [[Matrix3x3; 2]; 2],
+}
+
+impl Twist {
+    pub fn new(linear: Vector3, angular: Vector3) -> Self {
+        Twist { linear, angular }
+    }
+}
+
+impl Matrix6 {
+    pub fn new(
+        top_left: Matrix3x3,
+        top_right: Matrix3x3,
+        bottom_left: Matrix3x3,
+        bottom_right: Matrix3x3,
+    ) -> Self {
+        Matrix6 {
+            blocks: [[top_left, top_right], [bottom_left, bottom_right]],
+        }
+    }
+}
+
+impl Mul<Twist> for Matrix6 {
+    type Output = Twist;
+    fn mul(self, rhs: Twist) -> Self::Output {
+        let [[a, b], [c, d]] = self.blocks;
+        Twist {
+            linear: a * rhs.linear + b * rhs.angular,
+            angular: c * rhs.linear + d * rhs.angular,
+        }
+    }
+}
+
+/// Coefficients `(a, b)` of `hat(w)` and `hat(w)²` in the SO(3) left Jacobian.
+fn jacobian_coefficients(angle: f32) -> (f32, f32) {
+    if angle < SMALL_ANGLE {
+        let a2 = angle * angle;
+        return (0.5 - a2 / 24., 1. / 6. - a2 / 120.);
+    }
+    let a2 = angle * angle;
+    (
+        (1. - angle.cos()) / a2,
+        (angle - angle.sin()) / (a2 * angle),
+    )
+}
+
+/// Coefficient of `hat(w)²` in the inverse SO(3) Jacobians.
+fn inverse_jacobian_coefficient(angle: f32) -> f32 {
+    if angle < SMALL_ANGLE {
+        return 1. / 12. + angle * angle / 720.;
+    }
+    1. / (angle * angle) - (1. + angle.cos()) / (2. * angle * angle.sin())
+}
+
+impl SO3 {
+    pub fn identity() -> SO3 {
+        SO3 {
+            rotation: Quaternion::identity(),
+        }
+    }
+
+    pub fn from_quaternion(q: Quaternion) -> SO3 {
+        SO3 {
+            rotation: q.normalized(),
+        }
+    }
+
+    pub fn from_matrix(m: Matrix3x3) -> SO3 {
+        SO3 {
+            rotation: Quaternion::from_matrix(m),
+        }
+    }
+
+    pub fn quaternion(self) -> Quaternion {
+        self.rotation
+    }
+
+    pub fn matrix(self) -> Matrix3x3 {
+        self.rotation.to_matrix()
+    }
+
+    pub fn exp(omega: Vector3) -> SO3 {
+        SO3 {
+            rotation: Quaternion::from_rotation_vector(omega),
+        }
+    }
+
+    /// Rotation vector with angle in `[0, π]`.
+    pub fn log(self) -> Vector3 {
+        self.rotation.to_rotation_vector()
+    }
+
+    pub fn inverse(self) -> SO3 {
+        SO3 {
+            rotation: self.rotation.conjugate(),
+        }
+    }
+
+    pub fn rotate(self, v: Vector3) -> Vector3 {
+        self.rotation.rotate(v)
+    }
+
+    /// Maps tangent vectors at `self` to the identity: `self * exp(w) = exp(adjoint * w) * self`.
+    pub fn adjoint(self) -> Matrix3x3 {
+        self.matrix()
+    }
+
+    /// `exp(w + d) ≈ exp(left_jacobian(w) * d) * exp(w)`.
+    pub fn left_jacobian(omega: Vector3) -> Matrix3x3 {
+        let (a, b) = jacobian_coefficients(omega.magnitude());
+        let k = omega.hat();
+        Matrix3x3::identity() + scaled(k, a) + scaled(product(k, k), b)
+    }
+
+    /// `exp(w + d) ≈ exp(w) * exp(right_jacobian(w) * d)`.
+    pub fn right_jacobian(omega: Vector3) -> Matrix3x3 {
+        SO3::left_jacobian(omega * -1.)
+    }
+
+    pub fn left_jacobian_inverse(omega: Vector3) -> Matrix3x3 {
+        let c = inverse_jacobian_coefficient(omega.magnitude());
+        let k = omega.hat();
+        Matrix3x3::identity() - scaled(k, 0.5) + scaled(product(k, k), c)
+    }
+
+    pub fn right_jacobian_inverse(omega: Vector3) -> Matrix3x3 {
+        SO3::left_jacobian_inverse(omega * -1.)
+    }
+
+    /// `self * exp(tau)`.
+    pub fn boxplus(self, tau: Vector3) -> SO3 {
+        self * SO3::exp(tau)
+    }
+
+    /// `log(other⁻¹ * self)`, so that `other.boxplus(self.boxminus(other)) == self`.
+    pub fn boxminus(self, other: SO3) -> Vector3 {
+        (other.inverse() * self).log()
+    }
+}
+
+impl Mul for SO3 {
+    type Output = SO3;
+    fn mul(self, rhs: Self) -> Self::Output {
+        SO3 {
+            rotation: (self.rotation * rhs.rotation).normalized(),
+        }
+    }
+}
+
+impl Mul<Vector3> for SO3 {
+    type Output = Vector3;
+    fn mul(self, rhs: Vector3) -> Self::Output {
+        self.rotate(rhs)
+    }
+}
+
+/// Off-diagonal block of the SE(3) left Jacobian (Barfoot, eq. 7.86).
+fn q_block(twist: Twist) -> Matrix3x3 {
+    let angle = twist.angular.magnitude();
+    let p = twist.linear.hat();
+    let w = twist.angular.hat();
+    let a2 = angle * angle;
+
+    let (c1, c2, c3) = if angle < SMALL_ANGLE {
+        (
+            1. / 6. - a2 / 120.,
+            1. / 24. - a2 / 720.,
+            1. / 120. - a2 / 2520.,
+        )
+    } else {
+        let (sin, cos) = angle.sin_cos();
+        (
+            (angle - sin) / (a2 * angle),
+            (a2 + 2. * cos - 2.) / (2. * a2 * a2),
+            (2. * angle - 3. * sin + angle * cos) / (2. * a2 * a2 * angle),
+        )
+    };
+
+    let wp = product(w, p);
+    let pw = product(p, w);
+    let wpw = product(wp, w);
+    let wwp = product(w, wp);
+    let pww = product(pw, w);
+
+    scaled(p, 0.5)
+        + scaled(wp + pw + wpw, c1)
+        + scaled(wwp + pww - scaled(wpw, 3.), c2)
+        + scaled(product(wpw, w) + product(w, wpw), c3)
+}
+
+impl SE3 {
+    pub fn new(rotation: SO3, translation: Vector3) -> Self {
+        SE3 {
+            rotation,
+            translation,
+        }
+    }
+
+    pub fn identity() -> SE3 {
+        SE3::new(SO3::identity(), Vector3::default())
+    }
+
+    pub fn exp(twist: Twist) -> SE3 {
+        SE3 {
+            rotation: SO3::exp(twist.angular),
+            translation: SO3::left_jacobian(twist.angular) * twist.linear,
+        }
+    }
+
+    pub fn log(self) -> Twist {
+        let angular = self.rotation.log();
+        Twist {
+            linear: SO3::left_jacobian_inverse(angular) * self.translation,
+            angular,
+        }
+    }
+
+    pub fn inverse(self) -> SE3 {
+        let rotation = self.rotation.inverse();
+        SE3 {
+            rotation,
+            translation: rotation.rotate(self.translation) * -1.,
+        }
+    }
+
+    pub fn transform_point(self, p: Vector3) -> Vector3 {
+        self.rotation.rotate(p) + self.translation
+    }
+
+    /// `self * exp(xi) = exp(adjoint * xi) * self`.
+    pub fn adjoint(self) -> Matrix6 {
+        let r = self.rotation.matrix();
+        Matrix6::new(
+            r,
+            product(self.translation.hat(), r),
+            Matrix3x3::default(),
+            r,
+        )
+    }
+
+    /// `exp(xi + d) ≈ exp(left_jacobian(xi) * d) * exp(xi)`.
+    pub fn left_jacobian(twist: Twist) -> Matrix6 {
+        let j = SO3::left_jacobian(twist.angular);
+        Matrix6::new(j, q_block(twist), Matrix3x3::default(), j)
+    }
+
+    /// `exp(xi + d) ≈ exp(xi) * exp(right_jacobian(xi) * d)`.
+    pub fn right_jacobian(twist: Twist) -> Matrix6 {
+        SE3::left_jacobian(Twist::new(twist.linear * -1., twist.angular * -1.))
+    }
+
+    pub fn left_jacobian_inverse(twist: Twist) -> Matrix6 {
+        let j_inv = SO3::left_jacobian_inverse(twist.angular);
+        let q = q_block(twist);
+        Matrix6::new(
+            j_inv,
+            scaled(product(product(j_inv, q), j_inv), -1.),
+            Matrix3x3::default(),
+            j_inv,
+        )
+    }
+
+    pub fn right_jacobian_inverse(twist: Twist) -> Matrix6 {
+        SE3::left_jacobian_inverse(Twist::new(twist.linear * -1., twist.angular * -1.))
+    }
+
+    /// `self * exp(tau)`.
+    pub fn boxplus(self, tau: Twist) -> SE3 {
+        self * SE3::exp(tau)
+    }
+
+    /// `log(other⁻¹ * self)`.
+    pub fn boxminus(self, other: SE3) -> Twist {
+        (other.inverse() * self).log()
+    }
+}
+
+impl Mul for SE3 {
+    type Output = SE3;
+    fn mul(self, rhs: Self) -> Self::Output {
+        SE3 {
+            rotation: self.rotation * rhs.rotation,
+            translation: self.rotation.rotate(rhs.translation) + self.translation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
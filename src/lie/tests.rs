@@ -0,0 +1,211 @@
+use super::*;
+
+const H: f32 = 1e-3;
+
+fn vec_approx_eq(a: Vector3, b: Vector3, eps: f32) -> bool {
+    (a - b).magnitude() <= eps
+}
+
+fn twist_approx_eq(a: Twist, b: Twist, eps: f32) -> bool {
+    vec_approx_eq(a.linear, b.linear, eps) && vec_approx_eq(a.angular, b.angular, eps)
+}
+
+fn axis(i: usize) -> Vector3 {
+    [
+        Vector3::new(1., 0., 0.),
+        Vector3::new(0., 1., 0.),
+        Vector3::new(0., 0., 1.),
+    ][i]
+}
+
+fn twist_axis(i: usize) -> Twist {
+    if i < 3 {
+        Twist::new(axis(i), Vector3::default())
+    } else {
+        Twist::new(Vector3::default(), axis(i - 3))
+    }
+}
+
+fn twist_scale(t: Twist, s: f32) -> Twist {
+    Twist::new(t.linear * s, t.angular * s)
+}
+
+fn twist_add(a: Twist, b: Twist) -> Twist {
+    Twist::new(a.linear + b.linear, a.angular + b.angular)
+}
+
+/// Central difference of `f` along each tangent axis, as columns.
+fn numerical_so3(f: impl Fn(Vector3) -> Vector3) -> Matrix3x3 {
+    let cols = [0, 1, 2].map(|i| (f(axis(i) * H) - f(axis(i) * -H)) / (2. * H));
+    Matrix3x3::from_cols(cols[0], cols[1], cols[2])
+}
+
+fn assert_columns_match(analytic: Matrix3x3, numerical: Matrix3x3) {
+    for i in 0..3 {
+        assert!(
+            vec_approx_eq(analytic.col(i), numerical.col(i), 2e-3),
+            "column {i}: {} vs {}",
+            analytic.col(i),
+            numerical.col(i)
+        );
+    }
+}
+
+fn assert_six_columns_match(analytic: Matrix6, f: impl Fn(Twist) -> Twist) {
+    for i in 0..6 {
+        let plus = f(twist_scale(twist_axis(i), H));
+        let minus = f(twist_scale(twist_axis(i), -H));
+        let numerical = twist_scale(twist_add(plus, twist_scale(minus, -1.)), 0.5 / H);
+        let column = analytic * twist_axis(i);
+        assert!(
+            twist_approx_eq(column, numerical, 3e-3),
+            "column {i}: {:?} vs {:?}",
+            column,
+            numerical
+        );
+    }
+}
+
+fn sample_omegas() -> [Vector3; 3] {
+    [
+        Vector3::new(0.3, -0.5, 0.8),
+        Vector3::new(1e-5, 2e-5, 0.),
+        Vector3::new(-1.2, 0.4, 1.9),
+    ]
+}
+
+fn sample_twist() -> Twist {
+    Twist::new(Vector3::new(1., -2., 0.5), Vector3::new(0.4, 0.7, -0.9))
+}
+
+#[test]
+fn so3_exp_log_roundtrip() {
+    for omega in sample_omegas() {
+        assert!(vec_approx_eq(SO3::exp(omega).log(), omega, 1e-5));
+    }
+    assert_eq!(SO3::exp(Vector3::default()), SO3::identity());
+}
+
+#[test]
+fn so3_matches_matrix_exp() {
+    let omega = Vector3::new(0.3, -0.5, 0.8);
+    let from_quat = SO3::exp(omega).matrix();
+    let from_matrix = omega.hat().exp();
+
+    for i in 0..3 {
+        assert!(vec_approx_eq(from_quat.col(i), from_matrix.col(i), 1e-5));
+    }
+    let roundtrip = SO3::from_matrix(from_matrix);
+    assert!(vec_approx_eq(roundtrip.log(), omega, 1e-5));
+}
+
+#[test]
+fn so3_adjoint() {
+    let x = SO3::exp(Vector3::new(0.1, 0.9, -0.4));
+    let tau = Vector3::new(0.2, -0.3, 0.5);
+    let lhs = x * SO3::exp(tau);
+    let rhs = SO3::exp(x.adjoint() * tau) * x;
+
+    assert!(vec_approx_eq(lhs.boxminus(rhs), Vector3::default(), 1e-5));
+}
+
+#[test]
+fn so3_right_jacobian() {
+    for omega in sample_omegas() {
+        let x = SO3::exp(omega);
+        let numerical = numerical_so3(|d| (x.inverse() * SO3::exp(omega + d)).log());
+
+        assert_columns_match(SO3::right_jacobian(omega), numerical);
+    }
+}
+
+#[test]
+fn so3_left_jacobian() {
+    for omega in sample_omegas() {
+        let x = SO3::exp(omega);
+        let numerical = numerical_so3(|d| (SO3::exp(omega + d) * x.inverse()).log());
+
+        assert_columns_match(SO3::left_jacobian(omega), numerical);
+    }
+}
+
+#[test]
+fn so3_jacobian_inverses() {
+    for omega in sample_omegas() {
+        let x = SO3::exp(omega);
+        let right = numerical_so3(|d| (x * SO3::exp(d)).log());
+        let left = numerical_so3(|d| (SO3::exp(d) * x).log());
+
+        assert_columns_match(SO3::right_jacobian_inverse(omega), right);
+        assert_columns_match(SO3::left_jacobian_inverse(omega), left);
+    }
+}
+
+#[test]
+fn so3_boxplus_boxminus() {
+    let x = SO3::exp(Vector3::new(0.4, 0.1, -0.2));
+    let tau = Vector3::new(-0.3, 0.6, 0.2);
+    let y = x.boxplus(tau);
+
+    assert!(vec_approx_eq(y.boxminus(x), tau, 1e-5));
+}
+
+#[test]
+fn se3_exp_log_roundtrip() {
+    let twist = sample_twist();
+    let small = Twist::new(Vector3::new(1., 2., 3.), Vector3::new(1e-5, 0., 0.));
+
+    assert!(twist_approx_eq(SE3::exp(twist).log(), twist, 1e-5));
+    assert!(twist_approx_eq(SE3::exp(small).log(), small, 1e-5));
+}
+
+#[test]
+fn se3_composition_and_inverse() {
+    let a = SE3::exp(sample_twist());
+    let p = Vector3::new(1., 2., 3.);
+    let identity = a * a.inverse();
+
+    assert!(twist_approx_eq(identity.log(), Twist::default(), 1e-5));
+    assert!(vec_approx_eq(
+        a.inverse().transform_point(a.transform_point(p)),
+        p,
+        1e-5
+    ));
+}
+
+#[test]
+fn se3_adjoint() {
+    let x = SE3::exp(sample_twist());
+    let tau = Twist::new(Vector3::new(0.2, 0.1, -0.3), Vector3::new(0.3, -0.2, 0.1));
+    let lhs = x * SE3::exp(tau);
+    let rhs = SE3::exp(x.adjoint() * tau) * x;
+
+    assert!(twist_approx_eq(lhs.boxminus(rhs), Twist::default(), 1e-4));
+}
+
+#[test]
+fn se3_jacobians() {
+    let twist = sample_twist();
+    let x = SE3::exp(twist);
+
+    assert_six_columns_match(SE3::right_jacobian(twist), |d| {
+        (x.inverse() * SE3::exp(twist_add(twist, d))).log()
+    });
+    assert_six_columns_match(SE3::left_jacobian(twist), |d| {
+        (SE3::exp(twist_add(twist, d)) * x.inverse()).log()
+    });
+    assert_six_columns_match(SE3::right_jacobian_inverse(twist), |d| {
+        (x * SE3::exp(d)).log()
+    });
+    assert_six_columns_match(SE3::left_jacobian_inverse(twist), |d| {
+        (SE3::exp(d) * x).log()
+    });
+}
+
+#[test]
+fn se3_boxplus_boxminus() {
+    let x = SE3::exp(sample_twist());
+    let tau = Twist::new(Vector3::new(0.1, 0.2, 0.3), Vector3::new(-0.2, 0.1, 0.05));
+
+    assert!(twist_approx_eq(x.boxplus(tau).boxminus(x), tau, 1e-5));
+}
@@ -0,0 +1,314 @@
+use super::Matrix3x3;
+use crate::prelude::{MathError, Matrix6, Twist, Vector3};
+
+type Rows<const N: usize> = [[f32; N]; N];
+
+/// Square matrices the decompositions work on, viewed as `N` rows, together
+/// with the vector type they multiply.
+pub trait SquareMatrix<const N: usize>: Copy {
+    type Vector;
+
+    fn to_row_array(self) -> Rows<N>;
+    fn from_row_array(rows: Rows<N>) -> Self;
+    fn vector_to_array(v: Self::Vector) -> [f32; N];
+    fn vector_from_array(a: [f32; N]) -> Self::Vector;
+}
+
+impl SquareMatrix<3> for Matrix3x3 {
+    type Vector = Vector3;
+
+    fn to_row_array(self) -> Rows<3> {
+        [0, 1, 2].map(|r| [0, 1, 2].map(|c| self.get(r, c)))
+    }
+
+    fn from_row_array(rows: Rows<3>) -> Self {
+        let [r0, r1, r2] = rows.map(Self::vector_from_array);
+        Matrix3x3::from_rows(r0, r1, r2)
+    }
+
+    fn vector_to_array(v: Vector3) -> [f32; 3] {
+        [v.x, v.y, v.z]
+    }
+
+    fn vector_from_array(a: [f32; 3]) -> Vector3 {
+        Vector3::new(a[0], a[1], a[2])
+    }
+}
+
+impl SquareMatrix<6> for Matrix6 {
+    type Vector = Twist;
+
+    fn to_row_array(self) -> Rows<6> {
+        [0, 1, 2, 3, 4, 5]
+            .map(|r| [0, 1, 2, 3, 4, 5].map(|c| self.blocks[r / 3][c / 3].get(r % 3, c % 3)))
+    }
+
+    fn from_row_array(rows: Rows<6>) -> Self {
+        let block = |top: usize, left: usize| {
+            let row = |r: usize| {
+                let row = &rows[top + r][left..left + 3];
+                Vector3::new(row[0], row[1], row[2])
+            };
+            Matrix3x3::from_rows(row(0), row(1), row(2))
+        };
+        Matrix6::new(block(0, 0), block(0, 3), block(3, 0), block(3, 3))
+    }
+
+    fn vector_to_array(v: Twist) -> [f32; 6] {
+        let (l, a) = (v.linear, v.angular);
+        [l.x, l.y, l.z, a.x, a.y, a.z]
+    }
+
+    fn vector_from_array(a: [f32; 6]) -> Twist {
+        Twist::new(
+            Vector3::new(a[0], a[1], a[2]),
+            Vector3::new(a[3], a[4], a[5]),
+        )
+    }
+}
+
+fn identity_rows<const N: usize>() -> Rows<N> {
+    let mut rows = [[0.; N]; N];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[i] = 1.;
+    }
+    rows
+}
+
+/// Pivots at or below this magnitude are treated as zero.
+fn singular_tolerance<const N: usize>(a: &Rows<N>) -> f32 {
+    let scale = a.iter().flatten().fold(0f32, |max, x| max.max(x.abs()));
+    scale * (N + 1) as f32 * f32::EPSILON
+}
+
+fn back_substitute<const N: usize>(
+    u: &Rows<N>,
+    y: [f32; N],
+    tolerance: f32,
+) -> Result<[f32; N], MathError> {
+    let mut x = [0.; N];
+    for i in (0..N).rev() {
+        if u[i][i].abs() <= tolerance {
+            return Err(MathError::Singular);
+        }
+        let sum: f32 = (i + 1..N).map(|j| u[i][j] * x[j]).sum();
+        x[i] = (y[i] - sum) / u[i][i];
+    }
+    Ok(x)
+}
+
+fn forward_substitute<const N: usize>(l: &Rows<N>, b: [f32; N]) -> [f32; N] {
+    let mut y = [0.; N];
+    for i in 0..N {
+        let sum: f32 = (0..i).map(|j| l[i][j] * y[j]).sum();
+        y[i] = (b[i] - sum) / l[i][i];
+    }
+    y
+}
+
+/// LU factorization with partial pivoting, `P * A = L * U`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Lu<M = Matrix3x3, const N: usize = 3> {
+    /// Unit lower-triangular factor.
+    pub l: M,
+    /// Upper-triangular factor.
+    pub u: M,
+    /// Row `i` of `P * A` is row `permutation[i]` of `A`.
+    pub permutation: [usize; N],
+    tolerance: f32,
+}
+
+impl<M: SquareMatrix<N>, const N: usize> Lu<M, N> {
+    pub fn solve(&self, b: M::Vector) -> Result<M::Vector, MathError> {
+        let b = M::vector_to_array(b);
+        let pb = self.permutation.map(|i| b[i]);
+        let y = forward_substitute(&self.l.to_row_array(), pb);
+        back_substitute(&self.u.to_row_array(), y, self.tolerance).map(M::vector_from_array)
+    }
+
+    pub fn determinant(&self) -> f32 {
+        let p = self.permutation;
+        let swaps = (0..N)
+            .flat_map(|i| (i + 1..N).map(move |j| (i, j)))
+            .filter(|&(i, j)| p[i] > p[j])
+            .count();
+        let sign = if swaps.is_multiple_of(2) { 1. } else { -1. };
+        let u = self.u.to_row_array();
+        sign * (0..N).map(|i| u[i][i]).product::<f32>()
+    }
+}
+
+/// QR factorization by Householder reflections, `A = Q * R`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Qr<M = Matrix3x3, const N: usize = 3> {
+    /// Orthogonal factor.
+    pub q: M,
+    /// Upper-triangular factor.
+    pub r: M,
+    tolerance: f32,
+}
+
+impl<M: SquareMatrix<N>, const N: usize> Qr<M, N> {
+    pub fn solve(&self, b: M::Vector) -> Result<M::Vector, MathError> {
+        let q = self.q.to_row_array();
+        let b = M::vector_to_array(b);
+        let qt_b: [f32; N] = std::array::from_fn(|i| (0..N).map(|j| q[j][i] * b[j]).sum());
+        back_substitute(&self.r.to_row_array(), qt_b, self.tolerance).map(M::vector_from_array)
+    }
+}
+
+/// Cholesky factorization of a symmetric positive-definite matrix, `A = L * L^T`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Cholesky<M = Matrix3x3, const N: usize = 3> {
+    /// Lower-triangular factor with a positive diagonal.
+    pub l: M,
+}
+
+impl<M: SquareMatrix<N>, const N: usize> Cholesky<M, N> {
+    pub fn solve(&self, b: M::Vector) -> Result<M::Vector, MathError> {
+        let l = self.l.to_row_array();
+        let y = forward_substitute(&l, M::vector_to_array(b));
+        let lt: Rows<N> = std::array::from_fn(|r| std::array::from_fn(|c| l[c][r]));
+        back_substitute(&lt, y, 0.).map(M::vector_from_array)
+    }
+}
+
+fn lu<M: SquareMatrix<N>, const N: usize>(m: M) -> Lu<M, N> {
+    let mut a = m.to_row_array();
+    let tolerance = singular_tolerance(&a);
+    let mut l = [[0.; N]; N];
+    let mut permutation: [usize; N] = std::array::from_fn(|i| i);
+
+    for k in 0..N {
+        let pivot = (k..N)
+            .max_by(|&i, &j| a[i][k].abs().total_cmp(&a[j][k].abs()))
+            .unwrap_or(k);
+        a.swap(k, pivot);
+        l.swap(k, pivot);
+        permutation.swap(k, pivot);
+
+        l[k][k] = 1.;
+        if a[k][k] == 0. {
+            continue;
+        }
+        let pivot_row = a[k];
+        for i in k + 1..N {
+            let factor = a[i][k] / pivot_row[k];
+            l[i][k] = factor;
+            for (aij, akj) in a[i][k..].iter_mut().zip(&pivot_row[k..]) {
+                *aij -= factor * akj;
+            }
+        }
+    }
+
+    Lu {
+        l: M::from_row_array(l),
+        u: M::from_row_array(a),
+        permutation,
+        tolerance,
+    }
+}
+
+#[allow(clippy::needless_range_loop)]
+fn qr<M: SquareMatrix<N>, const N: usize>(m: M) -> Qr<M, N> {
+    let mut r = m.to_row_array();
+    let tolerance = singular_tolerance(&r);
+    let mut q = identity_rows::<N>();
+
+    for k in 0..N.saturating_sub(1) {
+        let norm = (k..N).map(|i| r[i][k] * r[i][k]).sum::<f32>().sqrt();
+        if norm == 0. {
+            continue;
+        }
+        let alpha = if r[k][k] > 0. { -norm } else { norm };
+        let mut v = [0.; N];
+        for i in k..N {
+            v[i] = r[i][k];
+        }
+        v[k] -= alpha;
+        let v_norm_sq: f32 = v.iter().map(|x| x * x).sum();
+        if v_norm_sq == 0. {
+            continue;
+        }
+
+        // R = H * R and Q = Q * H with H = I - 2 v v^T / (v^T v).
+        for j in 0..N {
+            let dot: f32 = (k..N).map(|i| v[i] * r[i][j]).sum();
+            let f = 2. * dot / v_norm_sq;
+            for i in k..N {
+                r[i][j] -= f * v[i];
+            }
+        }
+        for row in q.iter_mut() {
+            let dot: f32 = (k..N).map(|j| row[j] * v[j]).sum();
+            let f = 2. * dot / v_norm_sq;
+            for j in k..N {
+                row[j] -= f * v[j];
+            }
+        }
+        for row in r.iter_mut().skip(k + 1) {
+            row[k] = 0.;
+        }
+    }
+
+    Qr {
+        q: M::from_row_array(q),
+        r: M::from_row_array(r),
+        tolerance,
+    }
+}
+
+fn cholesky<M: SquareMatrix<N>, const N: usize>(m: M) -> Result<Cholesky<M, N>, MathError> {
+    let a = m.to_row_array();
+    let tolerance = singular_tolerance(&a);
+    let mut l = [[0.; N]; N];
+
+    let symmetric = (0..N).all(|i| (0..i).all(|j| (a[i][j] - a[j][i]).abs() <= tolerance));
+    if !symmetric {
+        return Err(MathError::NotPositiveDefinite);
+    }
+
+    for j in 0..N {
+        let diagonal = a[j][j] - (0..j).map(|k| l[j][k] * l[j][k]).sum::<f32>();
+        if diagonal <= tolerance {
+            return Err(MathError::NotPositiveDefinite);
+        }
+        l[j][j] = diagonal.sqrt();
+        for i in j + 1..N {
+            let sum: f32 = (0..j).map(|k| l[i][k] * l[j][k]).sum();
+            l[i][j] = (a[i][j] - sum) / l[j][j];
+        }
+    }
+
+    Ok(Cholesky {
+        l: M::from_row_array(l),
+    })
+}
+
+impl Matrix3x3 {
+    pub fn lu(self) -> Lu {
+        lu(self)
+    }
+
+    pub fn qr(self) -> Qr {
+        qr(self)
+    }
+
+    pub fn cholesky(self) -> Result<Cholesky, MathError> {
+        cholesky(self)
+    }
+}
+
+impl Matrix6 {
+    pub fn lu(self) -> Lu<Matrix6, 6> {
+        lu(self)
+    }
+
+    pub fn qr(self) -> Qr<Matrix6, 6> {
+        qr(self)
+    }
+
+    pub fn cholesky(self) -> Result<Cholesky<Matrix6, 6>, MathError> {
+        cholesky(self)
+    }
+}
@@ -0,0 +1,126 @@
+use std::f32::consts::PI;
+
+use super::{scaled, Matrix3x3};
+use crate::prelude::Vector3;
+
+const SMALL_ANGLE: f32 = 1e-4;
+
+/// Coefficients of the degree 6 diagonal Padé approximant of `exp`.
+const PADE: [f32; 7] = [
+    1.,
+    1. / 2.,
+    5. / 44.,
+    1. / 66.,
+    1. / 792.,
+    1. / 15840.,
+    1. / 665280.,
+];
+
+impl Matrix3x3 {
+    /// Inverse of [`Vector3::hat`]. Only the skew-symmetric part of `self` is
+    /// used.
+    pub fn vee(self) -> Vector3 {
+        Vector3::new(
+            self.get(2, 1) - self.get(1, 2),
+            self.get(0, 2) - self.get(2, 0),
+            self.get(1, 0) - self.get(0, 1),
+        ) * 0.5
+    }
+
+    pub fn is_skew_symmetric(self, eps: f32) -> bool {
+        (0..3).all(|i| (0..=i).all(|j| (self.get(i, j) + self.get(j, i)).abs() <= eps))
+    }
+
+    /// Matrix exponential. Skew-symmetric matrices use Rodrigues' formula and
+    /// map to rotations; anything else uses Padé scaling and squaring.
+    pub fn exp(self) -> Matrix3x3 {
+        let scale = self.data.iter().fold(0f32, |max, x| max.max(x.abs()));
+        if self.is_skew_symmetric(scale * 4. * f32::EPSILON) {
+            return rodrigues(self.vee());
+        }
+        pade_exp(self)
+    }
+
+    /// Logarithm of a rotation matrix, returned as the skew-symmetric generator
+    /// `hat(axis * angle)` with the angle in `[0, π]`.
+    pub fn log(self) -> Matrix3x3 {
+        let cos = ((self.trace() - 1.) * 0.5).clamp(-1., 1.);
+        let angle = cos.acos();
+        let skew = self.vee();
+
+        if angle < SMALL_ANGLE {
+            return skew.hat();
+        }
+        if angle < PI / 2. {
+            return (skew * (angle / angle.sin())).hat();
+        }
+
+        // Near π the skew part vanishes, so recover the axis from the symmetric
+        // part `cos·I + (1 - cos)·a·a^T` instead.
+        let d = 1. - cos;
+        let outer = |i: usize, j: usize| {
+            let identity = if i == j { cos } else { 0. };
+            ((self.get(i, j) + self.get(j, i)) * 0.5 - identity) / d
+        };
+        let k = (0..3)
+            .max_by(|&a, &b| outer(a, a).total_cmp(&outer(b, b)))
+            .unwrap_or(0);
+        let norm = outer(k, k).sqrt();
+        let mut axis = Vector3::new(outer(0, k), outer(1, k), outer(2, k)) / norm;
+        if axis.dot(skew) < 0. {
+            axis *= -1.;
+        }
+
+        (axis.normalize() * angle).hat()
+    }
+
+    pub fn trace(self) -> f32 {
+        self.data[0] + self.data[4] + self.data[8]
+    }
+}
+
+fn rodrigues(omega: Vector3) -> Matrix3x3 {
+    let angle = omega.magnitude();
+    let k = omega.hat();
+    let k2 = k * k;
+
+    let (a, b) = if angle < SMALL_ANGLE {
+        (1. - angle * angle / 6., 0.5 - angle * angle / 24.)
+    } else {
+        (angle.sin() / angle, (1. - angle.cos()) / (angle * angle))
+    };
+
+    Matrix3x3::identity() + scaled(k, a) + scaled(k2, b)
+}
+
+fn pade_exp(m: Matrix3x3) -> Matrix3x3 {
+    let norm = (0..3)
+        .map(|i| (0..3).map(|j| m.get(i, j).abs()).sum::<f32>())
+        .fold(0f32, f32::max);
+    let squarings = if norm > 0.5 {
+        (norm / 0.5).log2().ceil() as i32
+    } else {
+        0
+    };
+    let a = scaled(m, 0.5f32.powi(squarings));
+
+    let mut numerator = Matrix3x3::default();
+    let mut denominator = Matrix3x3::default();
+    let mut power = Matrix3x3::identity();
+    for (k, c) in PADE.iter().enumerate() {
+        let sign = if k % 2 == 0 { 1. } else { -1. };
+        numerator += scaled(power, *c);
+        denominator += scaled(power, sign * c);
+        power *= a;
+    }
+
+    // denominator⁻¹ · numerator, one column at a time.
+    let lu = denominator.lu();
+    let column = |i: usize| lu.solve(numerator.col(i)).unwrap_or(numerator.col(i));
+    let mut result = Matrix3x3::from_cols(column(0), column(1), column(2));
+
+    for _ in 0..squarings {
+        result *= result;
+    }
+    result
+}
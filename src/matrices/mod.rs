@@ -1,9 +1,14 @@
 use std::{
     fmt::Display,
-    ops::{Add, AddAssign, Mul, MulAssign},
+    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
 };
 
-use crate::prelude::Vector3;
+use crate::prelude::{Radians, Vector3};
+
+pub mod decomposition;
+mod exponential;
+mod orthonormal;
+mod svd;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub struct Matrix3x3 {
@@ -11,6 +16,7 @@ pub struct Matrix3x3 {
 }
 
 impl Matrix3x3 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         m0: f32,
         m1: f32,
@@ -31,6 +37,72 @@ impl Matrix3x3 {
         Matrix3x3::new(1., 0., 0., 0., 1., 0., 0., 0., 1.)
     }
 
+    pub fn from_rows(r0: Vector3, r1: Vector3, r2: Vector3) -> Matrix3x3 {
+        Matrix3x3::new(r0.x, r0.y, r0.z, r1.x, r1.y, r1.z, r2.x, r2.y, r2.z)
+    }
+
+    pub fn from_cols(c0: Vector3, c1: Vector3, c2: Vector3) -> Matrix3x3 {
+        Matrix3x3 {
+            data: [c0.x, c0.y, c0.z, c1.x, c1.y, c1.z, c2.x, c2.y, c2.z],
+        }
+    }
+
+    pub fn from_diagonal(diagonal: Vector3) -> Matrix3x3 {
+        Matrix3x3::new(diagonal.x, 0., 0., 0., diagonal.y, 0., 0., 0., diagonal.z)
+    }
+
+    /// Tangent-space matrix with tangent `t`, bitangent `b` and normal `n` as
+    /// columns, mapping tangent-space vectors into the space of the basis.
+    pub fn from_basis(t: Vector3, b: Vector3, n: Vector3) -> Matrix3x3 {
+        Matrix3x3::from_cols(t, b, n)
+    }
+
+    /// Rotation of `angle` about `axis`.
+    pub fn from_axis_angle(axis: Vector3, angle: impl Into<Radians>) -> Matrix3x3 {
+        let (sin, cos) = angle.into().sin_cos();
+        let Vector3 { x, y, z } = axis.normalize();
+        let t = 1. - cos;
+        Matrix3x3::new(
+            t * x * x + cos,
+            t * x * y - sin * z,
+            t * x * z + sin * y,
+            t * x * y + sin * z,
+            t * y * y + cos,
+            t * y * z - sin * x,
+            t * x * z - sin * y,
+            t * y * z + sin * x,
+            t * z * z + cos,
+        )
+    }
+
+    pub fn rotation_x(angle: impl Into<Radians>) -> Matrix3x3 {
+        let (sin, cos) = angle.into().sin_cos();
+        Matrix3x3::new(1., 0., 0., 0., cos, -sin, 0., sin, cos)
+    }
+
+    pub fn rotation_y(angle: impl Into<Radians>) -> Matrix3x3 {
+        let (sin, cos) = angle.into().sin_cos();
+        Matrix3x3::new(cos, 0., sin, 0., 1., 0., -sin, 0., cos)
+    }
+
+    pub fn rotation_z(angle: impl Into<Radians>) -> Matrix3x3 {
+        let (sin, cos) = angle.into().sin_cos();
+        Matrix3x3::new(cos, -sin, 0., sin, cos, 0., 0., 0., 1.)
+    }
+
+    /// Element at `row`, `col` (both zero based).
+    pub fn get(self, row: usize, col: usize) -> f32 {
+        self.data[col * 3 + row]
+    }
+
+    pub fn row(self, i: usize) -> Vector3 {
+        Vector3::new(self.data[i], self.data[3 + i], self.data[6 + i])
+    }
+
+    pub fn col(self, i: usize) -> Vector3 {
+        Vector3::new(self.data[i * 3], self.data[i * 3 + 1], self.data[i * 3 + 2])
+    }
+
     pub fn inverse(self) -> Matrix3x3 {
         let d = self.determinant();
         if d == 0. {
@@ -56,7 +128,7 @@ impl Matrix3x3 {
         Matrix3x3::new(m0, m1, m2, m3, m4, m5, m6, m7, m8)
     }
 
-    fn determinant(self) -> f32 {
+    pub fn determinant(self) -> f32 {
         self.data[0] * self.data[4] * self.data[8]
             + (self.data[3] * self.data[7] * self.data[2])
             + (self.data[6] * self.data[1] * self.data[5])
@@ -129,6 +201,22 @@ impl AddAssign for Matrix3x3 {
     }
 }
 
+impl Sub for Matrix3x3 {
+    type Output = Matrix3x3;
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl SubAssign for Matrix3x3 {
+    fn sub_assign(&mut self, rhs: Self) {
+        for i in 0..self.data.len() {
+            self.data[i] -= rhs.data[i];
+        }
+    }
+}
+
 impl Mul<f32> for Matrix3x3 {
     type Output = Matrix3x3;
     fn mul(self, rhs: f32) -> Self::Output {
@@ -154,6 +242,8 @@ impl MulAssign<f32> for Matrix3x3 {
     }
 }
 
+/// Composes transforms left to right: `a * b` applies `a` first and then `b`,
+/// which is the matrix product `b·a`.
 impl Mul<Matrix3x3> for Matrix3x3 {
     type Output = Matrix3x3;
     fn mul(self, rhs: Matrix3x3) -> Self::Output {
@@ -224,5 +314,16 @@ impl Mul<Vector3> for Matrix3x3 {
     }
 }
 
+/// `m * s` without the rounding applied by `Mul<f32>`.
+pub(crate) fn scaled(mut m: Matrix3x3, s: f32) -> Matrix3x3 {
+    m *= s;
+    m
+}
+
+/// Matrix product `a·b`.
+pub(crate) fn product(a: Matrix3x3, b: Matrix3x3) -> Matrix3x3 {
+    b * a
+}
+
 #[cfg(test)]
 mod tests;
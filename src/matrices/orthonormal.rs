@@ -0,0 +1,61 @@
+use super::Matrix3x3;
+use crate::prelude::Vector3;
+
+impl Matrix3x3 {
+    /// Gram-Schmidt orthonormalization of the columns, keeping the direction of
+    /// the first column and the handedness of the basis.
+    pub fn orthonormalize(self) -> Matrix3x3 {
+        let tolerance = 1e-6;
+
+        let c0 = self.col(0);
+        let x = if c0.magnitude() > tolerance {
+            c0.normalize()
+        } else {
+            Vector3::new(1., 0., 0.)
+        };
+
+        let c1 = self.col(1);
+        let c1 = c1 - x * x.dot(c1);
+        let y = if c1.magnitude() > tolerance {
+            c1.normalize()
+        } else {
+            x.any_orthogonal()
+        };
+
+        let c2 = self.col(2);
+        let z = x.cross(y);
+        let z = if c2.dot(z) < -tolerance { z * -1. } else { z };
+
+        Matrix3x3::from_cols(x, y, z)
+    }
+
+    /// Closest orthonormal matrix to `self`. Unlike Gram-Schmidt this treats all
+    /// columns equally, so no axis is favoured when correcting drift.
+    pub fn orthonormalize_symmetric(self) -> Matrix3x3 {
+        let (u, _, v) = self.svd();
+        v.transpose() * u
+    }
+
+    /// Splits `self` into the matrix product `R·S`, where `R` is a proper
+    /// rotation and `S` is symmetric, returned as `(R, S)`. Since `a * b`
+    /// applies `a` first, `self` equals `stretch * rotation` in operator form.
+    pub fn polar_decomposition(self) -> (Matrix3x3, Matrix3x3) {
+        let (u, sigma, v) = self.signed_svd();
+        let rotation = v.transpose() * u;
+        let stretch = v.transpose() * Matrix3x3::from_diagonal(sigma) * v;
+        (rotation, stretch)
+    }
+
+    pub fn is_orthonormal(self, eps: f32) -> bool {
+        let product = self.transpose() * self;
+        product
+            .data
+            .iter()
+            .zip(Matrix3x3::identity().data.iter())
+            .all(|(a, b)| (a - b).abs() <= eps)
+    }
+
+    pub fn is_rotation(self, eps: f32) -> bool {
+        self.is_orthonormal(eps) && (self.determinant() - 1.).abs() <= eps
+    }
+}
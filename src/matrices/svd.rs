@@ -0,0 +1,137 @@
+use super::Matrix3x3;
+use crate::prelude::Vector3;
+
+const MAX_SWEEPS: usize = 32;
+
+impl Matrix3x3 {
+    /// Singular value decomposition `self = U * diag(sigma) * V^T`.
+    ///
+    /// Singular values are non-negative and sorted in descending order. `U` and
+    /// `V` are orthogonal; `V` is always a proper rotation while `U` may contain a
+    /// reflection when the determinant of `self` is negative. Use
+    /// [`Matrix3x3::signed_svd`] when both factors must be rotations.
+    pub fn svd(self) -> (Matrix3x3, Vector3, Matrix3x3) {
+        let mut w = [self.col(0), self.col(1), self.col(2)];
+        let mut v = [
+            Vector3::new(1., 0., 0.),
+            Vector3::new(0., 1., 0.),
+            Vector3::new(0., 0., 1.),
+        ];
+
+        // One-sided Jacobi: rotate column pairs of `self * V` until they are
+        // mutually orthogonal, accumulating the same rotations into V.
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+                let alpha = w[p].dot(w[p]);
+                let beta = w[q].dot(w[q]);
+                let gamma = w[p].dot(w[q]);
+                if gamma == 0. || gamma.abs() <= f32::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (2. * gamma);
+                let t = zeta.signum() / (zeta.abs() + (1. + zeta * zeta).sqrt());
+                let c = 1. / (1. + t * t).sqrt();
+                let s = c * t;
+
+                let (wp, wq) = (w[p], w[q]);
+                w[p] = wp * c - wq * s;
+                w[q] = wp * s + wq * c;
+                let (vp, vq) = (v[p], v[q]);
+                v[p] = vp * c - vq * s;
+                v[q] = vp * s + vq * c;
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let mut sigma = [w[0].magnitude(), w[1].magnitude(), w[2].magnitude()];
+        let mut order = [0, 1, 2];
+        order.sort_by(|&a, &b| sigma[b].total_cmp(&sigma[a]));
+        let mut w = order.map(|i| w[i]);
+        let mut v = order.map(|i| v[i]);
+        sigma = order.map(|i| sigma[i]);
+
+        // Sorting permutes columns, which may flip the handedness of V.
+        if v[0].cross(v[1]).dot(v[2]) < 0. {
+            v[2] *= -1.;
+            w[2] *= -1.;
+        }
+
+        let tolerance = sigma[0] * 3. * f32::EPSILON;
+        let u0 = if sigma[0] > 0. {
+            w[0] / sigma[0]
+        } else {
+            Vector3::new(1., 0., 0.)
+        };
+        let u1 = if sigma[1] > tolerance {
+            w[1] / sigma[1]
+        } else {
+            u0.any_orthogonal()
+        };
+        let mut u2 = u0.cross(u1);
+        if sigma[2] > tolerance && u2.dot(w[2]) < 0. {
+            u2 *= -1.;
+        }
+
+        (
+            Matrix3x3::from_cols(u0, u1, u2),
+            Vector3::new(sigma[0], sigma[1], sigma[2]),
+            Matrix3x3::from_cols(v[0], v[1], v[2]),
+        )
+    }
+
+    /// Rotation-variant SVD: like [`Matrix3x3::svd`] but both `U` and `V` are
+    /// proper rotations. A reflection in `self` is expressed by negating the
+    /// smallest singular value instead.
+    pub fn signed_svd(self) -> (Matrix3x3, Vector3, Matrix3x3) {
+        let (u, mut sigma, v) = self.svd();
+        if u.determinant() >= 0. {
+            return (u, sigma, v);
+        }
+        sigma.z = -sigma.z;
+        (
+            Matrix3x3::from_cols(u.col(0), u.col(1), u.col(2) * -1.),
+            sigma,
+            v,
+        )
+    }
+
+    /// Moore-Penrose pseudo-inverse. Singular values at or below `eps` are
+    /// treated as zero.
+    pub fn pseudo_inverse(self, eps: f32) -> Matrix3x3 {
+        let (u, sigma, v) = self.svd();
+        let inv = [sigma.x, sigma.y, sigma.z].map(|s| if s > eps { 1. / s } else { 0. });
+
+        let mut result = Matrix3x3::default();
+        for row in 0..3 {
+            for col in 0..3 {
+                result.data[col * 3 + row] =
+                    (0..3).map(|k| v.get(row, k) * inv[k] * u.get(col, k)).sum();
+            }
+        }
+        result
+    }
+
+    /// Number of singular values greater than `eps`.
+    pub fn rank(self, eps: f32) -> usize {
+        let (_, sigma, _) = self.svd();
+        [sigma.x, sigma.y, sigma.z]
+            .iter()
+            .filter(|s| **s > eps)
+            .count()
+    }
+
+    /// Ratio of the largest to the smallest singular value. Singular matrices
+    /// return `f32::INFINITY`.
+    pub fn condition_number(self) -> f32 {
+        let (_, sigma, _) = self.svd();
+        if sigma.z == 0. {
+            return f32::INFINITY;
+        }
+        sigma.x / sigma.z
+    }
+}
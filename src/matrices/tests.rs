@@ -1,4 +1,4 @@
-use crate::prelude::Vector3;
+use crate::prelude::{Degrees, MathError, Matrix6, Radians, SquareMatrix, Twist, Vector3};
 
 use super::*;
 
@@ -125,3 +125,461 @@ fn transform() {
 
     assert_eq!(mat * vec, Vector3::new(0., 0., 1.));
 }
+
+fn approx_eq(a: Matrix3x3, b: Matrix3x3, eps: f32) -> bool {
+    a.data
+        .iter()
+        .zip(b.data.iter())
+        .all(|(x, y)| (x - y).abs() <= eps)
+}
+
+fn reconstruct(u: Matrix3x3, sigma: Vector3, v: Matrix3x3) -> Matrix3x3 {
+    let s = [sigma.x, sigma.y, sigma.z];
+    let mut result = Matrix3x3::default();
+    for row in 0..3 {
+        for col in 0..3 {
+            result.data[col * 3 + row] = (0..3).map(|k| u.get(row, k) * s[k] * v.get(col, k)).sum();
+        }
+    }
+    result
+}
+
+fn is_orthogonal(m: Matrix3x3) -> bool {
+    approx_eq(m.transpose() * m, Matrix3x3::identity(), 1e-5)
+}
+
+#[test]
+fn rows_and_cols() {
+    let mat = Matrix3x3::new(1., 2., 3., 4., 5., 6., 7., 8., 9.);
+
+    assert_eq!(mat.row(1), Vector3::new(4., 5., 6.));
+    assert_eq!(mat.col(2), Vector3::new(3., 6., 9.));
+    assert_eq!(mat.get(2, 0), 7.);
+    assert_eq!(
+        Matrix3x3::from_rows(mat.row(0), mat.row(1), mat.row(2)),
+        mat
+    );
+    assert_eq!(
+        Matrix3x3::from_cols(mat.col(0), mat.col(1), mat.col(2)),
+        mat
+    );
+}
+
+#[test]
+fn svd_reconstructs() {
+    let mat = Matrix3x3::new(3., 2., 5., 2., -1., 4., -1., 2., 1.);
+    let (u, sigma, v) = mat.svd();
+
+    assert!(approx_eq(reconstruct(u, sigma, v), mat, 1e-4));
+    assert!(sigma.x >= sigma.y && sigma.y >= sigma.z && sigma.z >= 0.);
+    assert!(is_orthogonal(u));
+    assert!(is_orthogonal(v));
+}
+
+#[test]
+fn svd_of_singular_matrix() {
+    let mat = Matrix3x3::new(1., 2., 3., 4., 5., 6., 7., 8., 9.);
+    let (u, sigma, v) = mat.svd();
+
+    assert!(approx_eq(reconstruct(u, sigma, v), mat, 1e-4));
+    assert!(sigma.z.abs() < 1e-4);
+    assert!(is_orthogonal(u));
+}
+
+#[test]
+fn svd_of_zero_matrix() {
+    let (u, sigma, v) = Matrix3x3::default().svd();
+
+    assert_eq!(sigma, Vector3::default());
+    assert!(is_orthogonal(u));
+    assert!(is_orthogonal(v));
+}
+
+#[test]
+fn signed_svd_gives_rotations() {
+    let reflection = Matrix3x3::new(1., 0., 0., 0., 2., 0., 0., 0., -3.);
+    let (u, sigma, v) = reflection.signed_svd();
+
+    assert!((u.determinant() - 1.).abs() < 1e-5);
+    assert!((v.determinant() - 1.).abs() < 1e-5);
+    assert!(sigma.z < 0.);
+    assert!(approx_eq(reconstruct(u, sigma, v), reflection, 1e-5));
+}
+
+#[test]
+fn pseudo_inverse_of_invertible() {
+    let mat = Matrix3x3::new(3., 2., 5., 2., -1., 4., -1., 2., 1.);
+    let pinv = mat.pseudo_inverse(1e-6);
+
+    assert!(approx_eq(pinv * mat, Matrix3x3::identity(), 1e-4));
+}
+
+#[test]
+fn pseudo_inverse_of_singular() {
+    let mat = Matrix3x3::new(1., 0., 0., 0., 2., 0., 0., 0., 0.);
+    let expected = Matrix3x3::new(1., 0., 0., 0., 0.5, 0., 0., 0., 0.);
+
+    assert!(approx_eq(mat.pseudo_inverse(1e-6), expected, 1e-6));
+}
+
+#[test]
+fn rank() {
+    assert_eq!(Matrix3x3::identity().rank(1e-5), 3);
+    assert_eq!(
+        Matrix3x3::new(1., 2., 3., 4., 5., 6., 7., 8., 9.).rank(1e-4),
+        2
+    );
+    assert_eq!(
+        Matrix3x3::new(1., 1., 1., 2., 2., 2., 3., 3., 3.).rank(1e-4),
+        1
+    );
+    assert_eq!(Matrix3x3::default().rank(1e-5), 0);
+}
+
+#[test]
+fn condition_number() {
+    let mat = Matrix3x3::new(4., 0., 0., 0., 2., 0., 0., 0., 1.);
+
+    assert!((mat.condition_number() - 4.).abs() < 1e-5);
+    assert_eq!(Matrix3x3::default().condition_number(), f32::INFINITY);
+}
+
+fn vec_approx_eq(a: Vector3, b: Vector3, eps: f32) -> bool {
+    (a - b).magnitude() <= eps
+}
+
+#[test]
+fn lu_factors() {
+    let mat = Matrix3x3::new(1., 2., 3., 4., 5., 6., 7., 8., 10.);
+    let lu = mat.lu();
+    let permuted = Matrix3x3::from_rows(
+        mat.row(lu.permutation[0]),
+        mat.row(lu.permutation[1]),
+        mat.row(lu.permutation[2]),
+    );
+
+    assert!(approx_eq(lu.u * lu.l, permuted, 1e-5));
+    assert!((lu.determinant() - mat.determinant()).abs() < 1e-4);
+}
+
+#[test]
+fn lu_solve() {
+    let mat = Matrix3x3::new(3., 2., 5., 2., -1., 4., -1., 2., 1.);
+    let x = Vector3::new(1., -2., 3.);
+    let solved = mat.lu().solve(mat * x).unwrap();
+
+    assert!(vec_approx_eq(solved, x, 1e-5));
+}
+
+#[test]
+fn lu_solve_singular() {
+    let mat = Matrix3x3::new(1., 2., 3., 4., 5., 6., 7., 8., 9.);
+
+    assert_eq!(
+        mat.lu().solve(Vector3::new(1., 1., 1.)),
+        Err(MathError::Singular)
+    );
+}
+
+#[test]
+fn qr_factors() {
+    let mat = Matrix3x3::new(12., -51., 4., 6., 167., -68., -4., 24., -41.);
+    let qr = mat.qr();
+
+    assert!(is_orthogonal(qr.q));
+    assert_eq!(qr.r.get(1, 0), 0.);
+    assert_eq!(qr.r.get(2, 0), 0.);
+    assert_eq!(qr.r.get(2, 1), 0.);
+    assert!(approx_eq(qr.r * qr.q, mat, 1e-3));
+}
+
+#[test]
+fn qr_solve() {
+    let mat = Matrix3x3::new(3., 2., 5., 2., -1., 4., -1., 2., 1.);
+    let x = Vector3::new(0.5, 4., -1.);
+    let solved = mat.qr().solve(mat * x).unwrap();
+
+    assert!(vec_approx_eq(solved, x, 1e-5));
+}
+
+#[test]
+fn qr_solve_singular() {
+    let mat = Matrix3x3::new(1., 1., 1., 2., 2., 2., 3., 3., 3.);
+
+    assert_eq!(
+        mat.qr().solve(Vector3::new(1., 2., 3.)),
+        Err(MathError::Singular)
+    );
+}
+
+#[test]
+fn cholesky_solve() {
+    let mat = Matrix3x3::new(4., 12., -16., 12., 37., -43., -16., -43., 98.);
+    let cholesky = mat.cholesky().unwrap();
+    let expected_l = Matrix3x3::new(2., 0., 0., 6., 1., 0., -8., 5., 3.);
+    let x = Vector3::new(1., 2., 3.);
+
+    assert!(approx_eq(cholesky.l, expected_l, 1e-5));
+    assert!(vec_approx_eq(cholesky.solve(mat * x).unwrap(), x, 1e-3));
+}
+
+#[test]
+fn cholesky_rejects_non_spd() {
+    let indefinite = Matrix3x3::new(1., 2., 0., 2., 1., 0., 0., 0., 1.);
+    let asymmetric = Matrix3x3::new(2., 1., 0., 0., 2., 0., 0., 0., 2.);
+
+    assert_eq!(indefinite.cholesky(), Err(MathError::NotPositiveDefinite));
+    assert_eq!(asymmetric.cholesky(), Err(MathError::NotPositiveDefinite));
+}
+
+/// Full 6x6 matrix with every block populated, so pivoting crosses blocks.
+fn coupled_matrix6() -> Matrix6 {
+    Matrix6::new(
+        Matrix3x3::new(1., 2., 0., -1., 3., 1., 0., 1., 2.),
+        Matrix3x3::new(4., 0., 1., 2., -2., 0., 1., 1., 1.),
+        Matrix3x3::new(0., 5., 1., 1., 0., -3., 2., 2., 0.),
+        Matrix3x3::new(3., 1., 0., 0., 1., 2., -1., 0., 4.),
+    )
+}
+
+fn rows_product(a: [[f32; 6]; 6], b: [[f32; 6]; 6]) -> [[f32; 6]; 6] {
+    std::array::from_fn(|i| std::array::from_fn(|j| (0..6).map(|k| a[i][k] * b[k][j]).sum()))
+}
+
+fn rows_approx_eq(a: [[f32; 6]; 6], b: [[f32; 6]; 6], eps: f32) -> bool {
+    a.iter()
+        .flatten()
+        .zip(b.iter().flatten())
+        .all(|(x, y)| (x - y).abs() <= eps)
+}
+
+fn twist_approx_eq(a: Twist, b: Twist, eps: f32) -> bool {
+    vec_approx_eq(a.linear, b.linear, eps) && vec_approx_eq(a.angular, b.angular, eps)
+}
+
+#[test]
+fn matrix6_lu_factors() {
+    let mat = coupled_matrix6();
+    let lu = mat.lu();
+    let rows = mat.to_row_array();
+    let permuted = lu.permutation.map(|i| rows[i]);
+
+    assert!(rows_approx_eq(
+        rows_product(lu.l.to_row_array(), lu.u.to_row_array()),
+        permuted,
+        1e-4
+    ));
+
+    // Block upper-triangular: the determinant factors over the diagonal blocks.
+    let [[a, b], [_, d]] = mat.blocks;
+    let triangular = Matrix6::new(a, b, Matrix3x3::default(), d);
+    let expected = a.determinant() * d.determinant();
+    assert!((triangular.lu().determinant() - expected).abs() < 1e-3);
+}
+
+#[test]
+fn matrix6_lu_and_qr_solve() {
+    let mat = coupled_matrix6();
+    let x = Twist::new(Vector3::new(1., -2., 0.5), Vector3::new(3., 0., -1.));
+    let b = mat * x;
+
+    assert!(twist_approx_eq(mat.lu().solve(b).unwrap(), x, 1e-4));
+    assert!(twist_approx_eq(mat.qr().solve(b).unwrap(), x, 1e-4));
+}
+
+#[test]
+fn matrix6_qr_factors() {
+    let mat = coupled_matrix6();
+    let qr = mat.qr();
+    let q = qr.q.to_row_array();
+    let r = qr.r.to_row_array();
+    let qt: [[f32; 6]; 6] = std::array::from_fn(|i| std::array::from_fn(|j| q[j][i]));
+
+    let identity = std::array::from_fn(|i| std::array::from_fn(|j| (i == j) as u8 as f32));
+    assert!(rows_approx_eq(rows_product(qt, q), identity, 1e-5));
+    assert!((0..6).all(|i| (0..i).all(|j| r[i][j] == 0.)));
+    assert!(rows_approx_eq(rows_product(q, r), mat.to_row_array(), 1e-4));
+}
+
+#[test]
+fn matrix6_solve_singular() {
+    let mut rows = coupled_matrix6().to_row_array();
+    rows[5] = rows[1];
+    let mat = Matrix6::from_row_array(rows);
+    let b = Twist::new(Vector3::new(1., 1., 1.), Vector3::new(1., 1., 1.));
+
+    assert_eq!(mat.lu().solve(b), Err(MathError::Singular));
+    assert_eq!(mat.qr().solve(b), Err(MathError::Singular));
+}
+
+#[test]
+fn matrix6_cholesky() {
+    // A^T A + I is symmetric positive-definite.
+    let rows = coupled_matrix6().to_row_array();
+    let at: [[f32; 6]; 6] = std::array::from_fn(|i| std::array::from_fn(|j| rows[j][i]));
+    let mut spd = rows_product(at, rows);
+    for (i, row) in spd.iter_mut().enumerate() {
+        row[i] += 1.;
+    }
+    let mat = Matrix6::from_row_array(spd);
+    let cholesky = mat.cholesky().unwrap();
+    let l = cholesky.l.to_row_array();
+    let lt: [[f32; 6]; 6] = std::array::from_fn(|i| std::array::from_fn(|j| l[j][i]));
+    let x = Twist::new(Vector3::new(0.5, 1., -1.), Vector3::new(2., -0.5, 0.));
+
+    assert!(rows_approx_eq(rows_product(l, lt), spd, 1e-3));
+    assert!(twist_approx_eq(cholesky.solve(mat * x).unwrap(), x, 1e-3));
+    assert_eq!(
+        coupled_matrix6().cholesky(),
+        Err(MathError::NotPositiveDefinite)
+    );
+}
+
+fn drifted_rotation() -> Matrix3x3 {
+    let (s, c) = 0.3f32.sin_cos();
+    let step = Matrix3x3::new(c, -s, 0., s, c, 0., 0., 0., 1.);
+    let mut mat = Matrix3x3::identity();
+    for _ in 0..10 {
+        mat *= step;
+    }
+    mat.data[1] += 0.01;
+    mat.data[5] -= 0.02;
+    mat
+}
+
+#[test]
+fn orthonormalize_gram_schmidt() {
+    let mat = drifted_rotation();
+    let fixed = mat.orthonormalize();
+
+    assert!(!mat.is_orthonormal(1e-3));
+    assert!(fixed.is_rotation(1e-5));
+    assert!(vec_approx_eq(fixed.col(0), mat.col(0).normalize(), 1e-6));
+}
+
+#[test]
+fn orthonormalize_keeps_reflection() {
+    let mat = Matrix3x3::new(1., 0., 0., 0.1, 1., 0., 0., 0., -1.);
+    let fixed = mat.orthonormalize();
+
+    assert!(fixed.is_orthonormal(1e-5));
+    assert!(!fixed.is_rotation(1e-5));
+}
+
+#[test]
+fn orthonormalize_symmetric() {
+    let mat = drifted_rotation();
+    let fixed = mat.orthonormalize_symmetric();
+
+    assert!(fixed.is_rotation(1e-5));
+    assert!(approx_eq(fixed, mat, 0.02));
+}
+
+#[test]
+fn polar_decomposition() {
+    let mat = Matrix3x3::new(3., 2., 5., 2., -1., 4., -1., 2., 1.);
+    let (rotation, stretch) = mat.polar_decomposition();
+
+    assert!(rotation.is_rotation(1e-5));
+    assert!(approx_eq(stretch, stretch.transpose(), 1e-4));
+    assert!(approx_eq(stretch * rotation, mat, 1e-4));
+}
+
+#[test]
+fn orthonormal_checks() {
+    let rotation = Matrix3x3::new(0., -1., 0., 1., 0., 0., 0., 0., 1.);
+    let reflection = Matrix3x3::new(1., 0., 0., 0., 1., 0., 0., 0., -1.);
+
+    assert!(rotation.is_rotation(1e-6));
+    assert!(reflection.is_orthonormal(1e-6));
+    assert!(!reflection.is_rotation(1e-6));
+    assert!(!(Matrix3x3::identity() * 2.).is_orthonormal(1e-6));
+}
+
+#[test]
+fn hat_and_vee() {
+    let v = Vector3::new(1., -2., 3.);
+    let u = Vector3::new(0.5, 4., -1.);
+
+    assert_eq!(v.hat() * u, v.cross(u));
+    assert_eq!(v.hat().vee(), v);
+    assert!(v.hat().is_skew_symmetric(0.));
+}
+
+#[test]
+fn exp_of_skew_symmetric_is_rotation() {
+    let angle = 0.7f32;
+    let (s, c) = angle.sin_cos();
+    let expected = Matrix3x3::new(c, -s, 0., s, c, 0., 0., 0., 1.);
+    let rotation = Vector3::new(0., 0., angle).hat().exp();
+
+    assert!(approx_eq(rotation, expected, 1e-6));
+    assert!(rotation.is_rotation(1e-5));
+    assert_eq!(Matrix3x3::default().exp(), Matrix3x3::identity());
+}
+
+#[test]
+fn exp_of_general_matrix() {
+    let diagonal = Matrix3x3::from_diagonal(Vector3::new(1., -2., 3.));
+    let expected = Matrix3x3::from_diagonal(Vector3::new(1f32.exp(), (-2f32).exp(), 3f32.exp()));
+    let nilpotent = Matrix3x3::new(0., 1., 2., 0., 0., 3., 0., 0., 0.);
+    let expected_nilpotent = Matrix3x3::new(1., 1., 3.5, 0., 1., 3., 0., 0., 1.);
+
+    assert!(approx_eq(diagonal.exp(), expected, 1e-4));
+    assert!(approx_eq(nilpotent.exp(), expected_nilpotent, 1e-5));
+}
+
+#[test]
+fn log_of_rotation() {
+    for omega in [
+        Vector3::new(0.3, -0.2, 0.5),
+        Vector3::new(1e-6, 0., 0.),
+        Vector3::new(0., 2.5, 1.),
+        Vector3::new(0., 0., std::f32::consts::PI - 1e-3),
+    ] {
+        let generator = omega.hat().exp().log();
+
+        assert!(generator.is_skew_symmetric(1e-6));
+        assert!(vec_approx_eq(generator.vee(), omega, 1e-3));
+    }
+    assert_eq!(Matrix3x3::identity().log(), Matrix3x3::default());
+}
+
+#[test]
+fn subtraction() {
+    let expected = Matrix3x3::new(0., 0., 0., 1., 1., 1., 2., 2., 2.);
+    let mut mat3 = Matrix3x3::new(1., 1., 1., 2., 2., 2., 3., 3., 3.);
+    let ones = Matrix3x3::new(1., 1., 1., 1., 1., 1., 1., 1., 1.);
+
+    assert_eq!(mat3 - ones, expected);
+    mat3 -= ones;
+    assert_eq!(mat3, expected);
+}
+
+#[test]
+fn rotation_constructors() {
+    let quarter = Degrees(90.);
+    let x = Vector3::new(1., 0., 0.);
+    let y = Vector3::new(0., 1., 0.);
+    let z = Vector3::new(0., 0., 1.);
+
+    assert!(vec_approx_eq(Matrix3x3::rotation_x(quarter) * y, z, 1e-6));
+    assert!(vec_approx_eq(Matrix3x3::rotation_y(quarter) * z, x, 1e-6));
+    assert!(vec_approx_eq(Matrix3x3::rotation_z(quarter) * x, y, 1e-6));
+    assert!(approx_eq(
+        Matrix3x3::from_axis_angle(Vector3::new(0., 0., 2.), Radians(0.4)),
+        Matrix3x3::rotation_z(Radians(0.4)),
+        1e-6
+    ));
+    assert!(Matrix3x3::from_axis_angle(Vector3::new(1., 2., 3.), Radians(1.1)).is_rotation(1e-5));
+}
+
+#[test]
+fn from_basis() {
+    let n = Vector3::new(1., 1., 0.).normalize();
+    let (t, b) = n.orthonormal_basis();
+    let tbn = Matrix3x3::from_basis(t, b, n);
+
+    assert!(tbn.is_rotation(1e-5));
+    assert!(vec_approx_eq(tbn * Vector3::new(0., 0., 1.), n, 1e-6));
+}
@@ -1,6 +1,10 @@
 use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
 
-use crate::prelude::Vector3;
+use crate::prelude::{Matrix3x3, Radians, Vector3};
+
+mod statistics;
+
+const SMALL_ANGLE: f32 = 1e-3;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub struct Quaternion {
@@ -22,6 +26,124 @@ impl Quaternion {
         Quaternion { scalar, vector }
     }
 
+    pub fn identity() -> Quaternion {
+        Quaternion::new(1., Vector3::default())
+    }
+
+    /// Rotation of `angle` about `axis`.
+    pub fn from_axis_angle(axis: Vector3, angle: impl Into<Radians>) -> Quaternion {
+        let (sin, cos) = (angle.into() * 0.5).sin_cos();
+        Quaternion::new(cos, axis.normalize() * sin)
+    }
+
+    /// Rotation of `|v|` radians about the direction of `v`.
+    pub fn from_rotation_vector(v: Vector3) -> Quaternion {
+        let angle = v.magnitude();
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        let k = if angle < SMALL_ANGLE {
+            0.5 - angle * angle / 48.
+        } else {
+            sin / angle
+        };
+        Quaternion::new(cos, v * k)
+    }
+
+    /// Inverse of [`Quaternion::from_rotation_vector`] for unit quaternions,
+    /// with the angle in `[0, π]`.
+    pub fn to_rotation_vector(self) -> Vector3 {
+        let q = if self.scalar < 0. { self * -1. } else { self };
+        let n = q.vector.magnitude();
+        if n < SMALL_ANGLE {
+            return q.vector * (2. / q.scalar);
+        }
+        q.vector * (2. * n.atan2(q.scalar) / n)
+    }
+
+    /// Unit quaternion of the rotation matrix `m` (Shepperd's method).
+    pub fn from_matrix(m: Matrix3x3) -> Quaternion {
+        let trace = m.trace();
+        let q = if trace > 0. {
+            let s = (trace + 1.).sqrt() * 2.;
+            Quaternion::new(
+                0.25 * s,
+                Vector3::new(
+                    (m.get(2, 1) - m.get(1, 2)) / s,
+                    (m.get(0, 2) - m.get(2, 0)) / s,
+                    (m.get(1, 0) - m.get(0, 1)) / s,
+                ),
+            )
+        } else if m.get(0, 0) > m.get(1, 1) && m.get(0, 0) > m.get(2, 2) {
+            let s = (1. + m.get(0, 0) - m.get(1, 1) - m.get(2, 2)).sqrt() * 2.;
+            Quaternion::new(
+                (m.get(2, 1) - m.get(1, 2)) / s,
+                Vector3::new(
+                    0.25 * s,
+                    (m.get(0, 1) + m.get(1, 0)) / s,
+                    (m.get(0, 2) + m.get(2, 0)) / s,
+                ),
+            )
+        } else if m.get(1, 1) > m.get(2, 2) {
+            let s = (1. + m.get(1, 1) - m.get(0, 0) - m.get(2, 2)).sqrt() * 2.;
+            Quaternion::new(
+                (m.get(0, 2) - m.get(2, 0)) / s,
+                Vector3::new(
+                    (m.get(0, 1) + m.get(1, 0)) / s,
+                    0.25 * s,
+                    (m.get(1, 2) + m.get(2, 1)) / s,
+                ),
+            )
+        } else {
+            let s = (1. + m.get(2, 2) - m.get(0, 0) - m.get(1, 1)).sqrt() * 2.;
+            Quaternion::new(
+                (m.get(1, 0) - m.get(0, 1)) / s,
+                Vector3::new(
+                    (m.get(0, 2) + m.get(2, 0)) / s,
+                    (m.get(1, 2) + m.get(2, 1)) / s,
+                    0.25 * s,
+                ),
+            )
+        };
+        q.normalized()
+    }
+
+    /// Shortest rotation taking the direction of `from` onto the direction of
+    /// `to`. Opposite vectors produce a half turn about an arbitrary
+    /// perpendicular axis.
+    pub fn from_rotation_arc(from: Vector3, to: Vector3) -> Quaternion {
+        let from = from.normalize();
+        let to = to.normalize();
+        let d = from.dot(to);
+        if d < -1. + 1e-6 {
+            return Quaternion::new(0., from.any_orthogonal());
+        }
+        Quaternion::new(1. + d, from.cross(to)).normalized()
+    }
+
+    /// Rotation that points the local +Z axis along `forward` and keeps the
+    /// local +Y axis as close to `up` as possible.
+    pub fn look_rotation(forward: Vector3, up: Vector3) -> Quaternion {
+        let forward = forward.normalize();
+        let right = up.cross(forward);
+        if right.magnitude() < 1e-6 {
+            return Quaternion::from_rotation_arc(Vector3::new(0., 0., 1.), forward);
+        }
+        let right = right.normalize();
+        let up = forward.cross(right);
+        Quaternion::from_matrix(Matrix3x3::from_cols(right, up, forward))
+    }
+
+    pub fn scalar(self) -> f32 {
+        self.scalar
+    }
+
+    pub fn vector(self) -> Vector3 {
+        self.vector
+    }
+
+    pub fn dot(self, other: Quaternion) -> f32 {
+        self.scalar * other.scalar + self.vector.dot(other.vector)
+    }
+
     pub fn norm(self) -> f32 {
         let n = (self.scalar * self.scalar)
             + (self.vector.x * self.vector.x)
@@ -38,6 +160,19 @@ impl Quaternion {
         }
     }
 
+    /// Full precision unit quaternion. Unlike [`Quaternion::normalize`] the
+    /// components are not rounded.
+    pub fn normalized(self) -> Quaternion {
+        let n = self.dot(self).sqrt();
+        if n == 0. {
+            return self;
+        }
+        Quaternion {
+            scalar: self.scalar / n,
+            vector: self.vector / n,
+        }
+    }
+
     pub fn conjugate(self) -> Quaternion {
         Quaternion {
             scalar: self.scalar,
@@ -58,11 +193,71 @@ impl Quaternion {
         }
     }
 
-    pub fn rotate_angle(self, u_angle: f32, u_axis: &Vector3) -> Vector3 {
+    /// Rotates `v` by this unit quaternion.
+    pub fn rotate(self, v: Vector3) -> Vector3 {
+        let t = self.vector.cross(v) * 2.;
+        v + t * self.scalar + self.vector.cross(t)
+    }
+
+    /// Splits this unit quaternion into `swing * twist`, where `twist` rotates
+    /// about `axis` and `swing` rotates about an axis perpendicular to it.
+    pub fn swing_twist(self, axis: Vector3) -> (Quaternion, Quaternion) {
+        let axis = axis.normalize();
+        let projected = axis * self.vector.dot(axis);
+        let twist = Quaternion::new(self.scalar, projected);
+        if twist.dot(twist) < 1e-12 {
+            return (self, Quaternion::identity());
+        }
+        let twist = twist.normalized();
+        (self * twist.conjugate(), twist)
+    }
+
+    /// Time derivative of this orientation under the world-frame angular
+    /// velocity `omega`, `½·ω·q`.
+    pub fn derivative(self, omega: Vector3) -> Quaternion {
+        Quaternion::new(0., omega) * self * 0.5
+    }
+
+    /// First-order integration of the world-frame angular velocity `omega` over
+    /// `dt`, renormalized afterwards.
+    pub fn integrate(self, omega: Vector3, dt: f32) -> Quaternion {
+        (self + self.derivative(omega) * dt).normalized()
+    }
+
+    /// Exact integration of a constant world-frame angular velocity `omega`
+    /// over `dt` through the exponential map.
+    pub fn integrate_exact(self, omega: Vector3, dt: f32) -> Quaternion {
+        (Quaternion::from_rotation_vector(omega * dt) * self).normalized()
+    }
+
+    /// Constant world-frame angular velocity that takes `q0` to `q1` in `dt`
+    /// along the shortest path.
+    pub fn angular_velocity_between(q0: Quaternion, q1: Quaternion, dt: f32) -> Vector3 {
+        (q1 * q0.conjugate()).to_rotation_vector() / dt
+    }
+
+    /// Rotation matrix of this unit quaternion.
+    pub fn to_matrix(self) -> Matrix3x3 {
+        let w = self.scalar;
+        let Vector3 { x, y, z } = self.vector;
+        Matrix3x3::new(
+            1. - 2. * (y * y + z * z),
+            2. * (x * y - w * z),
+            2. * (x * z + w * y),
+            2. * (x * y + w * z),
+            1. - 2. * (x * x + z * z),
+            2. * (y * z - w * x),
+            2. * (x * z - w * y),
+            2. * (y * z + w * x),
+            1. - 2. * (x * x + y * y),
+        )
+    }
+
+    pub fn rotate_angle(self, u_angle: impl Into<Radians>, u_axis: &Vector3) -> Vector3 {
         let p_quat = Quaternion::new(0., self.vector);
         u_axis.normalize();
 
-        let mut r_quat = Quaternion::new(u_angle, *u_axis);
+        let mut r_quat = Quaternion::new(u_angle.into().0, *u_axis);
         r_quat.normalize();
         let inverse = r_quat.inverse();
 
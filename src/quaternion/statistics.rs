@@ -0,0 +1,132 @@
+use super::Quaternion;
+use crate::prelude::{Matrix3x3, Vector3};
+
+const MAX_SWEEPS: usize = 32;
+
+type Symmetric4 = [[f32; 4]; 4];
+
+/// Eigenvalues and eigenvectors (as columns of the second value) of a
+/// symmetric 4x4 matrix, by cyclic Jacobi rotations.
+fn symmetric_eigen(mut a: Symmetric4) -> ([f32; 4], Symmetric4) {
+    let mut v = [[0.; 4]; 4];
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = 1.;
+    }
+
+    for _ in 0..MAX_SWEEPS {
+        let off: f32 = (0..4)
+            .flat_map(|i| (i + 1..4).map(move |j| (i, j)))
+            .map(|(i, j)| a[i][j] * a[i][j])
+            .sum();
+        if off < 1e-20 {
+            break;
+        }
+
+        for p in 0..4 {
+            for q in p + 1..4 {
+                if a[p][q] == 0. {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (2. * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.).sqrt());
+                let c = 1. / (t * t + 1.).sqrt();
+                let s = t * c;
+
+                for row in a.iter_mut() {
+                    let (akp, akq) = (row[p], row[q]);
+                    row[p] = c * akp - s * akq;
+                    row[q] = s * akp + c * akq;
+                }
+                let (row_p, row_q) = (a[p], a[q]);
+                a[p] = [0, 1, 2, 3].map(|k| c * row_p[k] - s * row_q[k]);
+                a[q] = [0, 1, 2, 3].map(|k| s * row_p[k] + c * row_q[k]);
+                for row in v.iter_mut() {
+                    let (vp, vq) = (row[p], row[q]);
+                    row[p] = c * vp - s * vq;
+                    row[q] = s * vp + c * vq;
+                }
+            }
+        }
+    }
+
+    ([a[0][0], a[1][1], a[2][2], a[3][3]], v)
+}
+
+fn components(q: Quaternion) -> [f32; 4] {
+    [q.scalar, q.vector.x, q.vector.y, q.vector.z]
+}
+
+impl Quaternion {
+    /// Weighted mean rotation (Markley et al., 2007): the eigenvector of the
+    /// largest eigenvalue of `Σ wᵢ·qᵢ·qᵢᵀ`. Insensitive to the sign of each
+    /// input quaternion. An empty slice returns the identity.
+    pub fn weighted_average(rotations: &[(Quaternion, f32)]) -> Quaternion {
+        let mut m = [[0.; 4]; 4];
+        for (q, w) in rotations {
+            let c = components(q.normalized());
+            for i in 0..4 {
+                for j in 0..4 {
+                    m[i][j] += w * c[i] * c[j];
+                }
+            }
+        }
+        if m.iter().flatten().all(|x| *x == 0.) {
+            return Quaternion::identity();
+        }
+
+        let (values, vectors) = symmetric_eigen(m);
+        let best = (0..4)
+            .max_by(|&a, &b| values[a].total_cmp(&values[b]))
+            .unwrap_or(0);
+        let mean = Quaternion::new(
+            vectors[0][best],
+            Vector3::new(vectors[1][best], vectors[2][best], vectors[3][best]),
+        )
+        .normalized();
+
+        if mean.scalar < 0. {
+            mean * -1.
+        } else {
+            mean
+        }
+    }
+
+    /// Rotation closest to the mean of the rotation matrices in the Frobenius
+    /// norm (chordal L2 mean). An empty slice returns the identity.
+    pub fn chordal_mean(rotations: &[Quaternion]) -> Quaternion {
+        if rotations.is_empty() {
+            return Quaternion::identity();
+        }
+        let sum = rotations.iter().fold(Matrix3x3::default(), |acc, q| {
+            acc + q.normalized().to_matrix()
+        });
+        let (rotation, _) = sum.polar_decomposition();
+        Quaternion::from_matrix(rotation)
+    }
+
+    /// Angle in `[0, π]` of the rotation taking `a` to `b`.
+    pub fn angle_between(a: Quaternion, b: Quaternion) -> f32 {
+        let relative = b * a.conjugate();
+        2. * relative.vector.magnitude().atan2(relative.scalar.abs())
+    }
+
+    /// Riemannian distance on SO(3), which is the angle of the relative rotation.
+    pub fn geodesic_distance(self, other: Quaternion) -> f32 {
+        Quaternion::angle_between(self, other)
+    }
+
+    /// Weighted mean squared geodesic distance to the weighted average, in
+    /// radians squared.
+    pub fn variance(rotations: &[(Quaternion, f32)]) -> f32 {
+        let total: f32 = rotations.iter().map(|(_, w)| w).sum();
+        if total == 0. {
+            return 0.;
+        }
+        let mean = Quaternion::weighted_average(rotations);
+        rotations
+            .iter()
+            .map(|(q, w)| w * mean.geodesic_distance(q.normalized()).powi(2))
+            .sum::<f32>()
+            / total
+    }
+}
@@ -1,5 +1,7 @@
 use super::*;
 
+use crate::prelude::Degrees;
+
 #[test]
 fn create_default() {
     let expected = Quaternion {
@@ -21,6 +23,7 @@ fn create_new() {
 }
 
 #[test]
+#[allow(unused_assignments)]
 fn assign_quaternion() {
     let expected = Quaternion::new(2., Vector3::new(1., 2., 3.));
     let mut quat = Quaternion::default();
@@ -145,3 +148,260 @@ fn inverse_quat() {
 
     assert_eq!(q.inverse(), expected);
 }
+
+fn vec_approx_eq(a: Vector3, b: Vector3, eps: f32) -> bool {
+    (a - b).magnitude() <= eps
+}
+
+#[test]
+fn normalized_keeps_precision() {
+    let q = Quaternion::new(1., Vector3::new(1., 2., 3.)).normalized();
+
+    assert!((q.dot(q) - 1.).abs() < 1e-6);
+    assert_eq!(Quaternion::default().normalized(), Quaternion::default());
+}
+
+#[test]
+fn rotate_vector() {
+    let q = Quaternion::from_axis_angle(
+        Vector3::new(0., 0., 2.),
+        Radians(std::f32::consts::FRAC_PI_2),
+    );
+
+    assert!(vec_approx_eq(
+        q.rotate(Vector3::new(1., 0., 0.)),
+        Vector3::new(0., 1., 0.),
+        1e-6
+    ));
+    assert_eq!(
+        Quaternion::identity().rotate(Vector3::new(1., 2., 3.)),
+        Vector3::new(1., 2., 3.)
+    );
+}
+
+#[test]
+fn matrix_roundtrip() {
+    for (axis, angle) in [
+        (Vector3::new(1., 2., 3.), 0.8),
+        (Vector3::new(1., 0., 0.), 3.),
+        (Vector3::new(0., 1., 0.), 3.),
+        (Vector3::new(0., 0., 1.), 3.),
+    ] {
+        let q = Quaternion::from_axis_angle(axis, Radians(angle));
+        let m = q.to_matrix();
+        let v = Vector3::new(0.3, -1., 2.);
+        let back = Quaternion::from_matrix(m);
+
+        assert!(vec_approx_eq(m * v, q.rotate(v), 1e-5));
+        assert!((back.dot(q).abs() - 1.).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn swing_twist_decomposition() {
+    let axis = Vector3::new(0., 1., 0.);
+    let q = Quaternion::from_axis_angle(Vector3::new(0., 1., 0.), Radians(0.6))
+        * Quaternion::from_axis_angle(Vector3::new(1., 0., 0.), Radians(0.3));
+    let (swing, twist) = q.swing_twist(axis);
+    let recomposed = swing * twist;
+
+    assert!((recomposed.dot(q) - 1.).abs() < 1e-5);
+    assert!(vec_approx_eq(
+        twist.vector().cross(axis),
+        Vector3::default(),
+        1e-6
+    ));
+    assert!(swing.vector().dot(axis).abs() < 1e-6);
+}
+
+#[test]
+fn swing_twist_pure_twist_and_pure_swing() {
+    let axis = Vector3::new(0., 0., 1.);
+    let twist_only = Quaternion::from_axis_angle(axis, Radians(1.2));
+    let swing_only =
+        Quaternion::from_axis_angle(Vector3::new(1., 0., 0.), Radians(std::f32::consts::PI));
+
+    let (swing, twist) = twist_only.swing_twist(axis);
+    assert!((swing.dot(Quaternion::identity()) - 1.).abs() < 1e-6);
+    assert!((twist.dot(twist_only) - 1.).abs() < 1e-6);
+
+    let (swing, twist) = swing_only.swing_twist(axis);
+    assert_eq!(twist, Quaternion::identity());
+    assert_eq!(swing, swing_only);
+}
+
+#[test]
+fn rotation_arc() {
+    let from = Vector3::new(1., 0., 0.);
+    for to in [
+        Vector3::new(0., 2., 0.),
+        Vector3::new(1., 1., 1.),
+        Vector3::new(1., 0., 0.),
+        Vector3::new(-3., 0., 0.),
+    ] {
+        let q = Quaternion::from_rotation_arc(from, to);
+
+        assert!(vec_approx_eq(q.rotate(from), to.normalize(), 1e-5));
+    }
+}
+
+#[test]
+fn look_rotation() {
+    let forward = Vector3::new(1., 0., 1.);
+    let q = Quaternion::look_rotation(forward, Vector3::new(0., 1., 0.));
+
+    assert!(vec_approx_eq(
+        q.rotate(Vector3::new(0., 0., 1.)),
+        forward.normalize(),
+        1e-5
+    ));
+    assert!(vec_approx_eq(
+        q.rotate(Vector3::new(0., 1., 0.)),
+        Vector3::new(0., 1., 0.),
+        1e-5
+    ));
+
+    let straight_up = Quaternion::look_rotation(Vector3::new(0., 1., 0.), Vector3::new(0., 1., 0.));
+    assert!(vec_approx_eq(
+        straight_up.rotate(Vector3::new(0., 0., 1.)),
+        Vector3::new(0., 1., 0.),
+        1e-5
+    ));
+}
+
+#[test]
+fn rotation_vector_roundtrip() {
+    let v = Vector3::new(0.4, -1.1, 0.7);
+
+    assert!(vec_approx_eq(
+        Quaternion::from_rotation_vector(v).to_rotation_vector(),
+        v,
+        1e-5
+    ));
+    assert_eq!(
+        Quaternion::from_rotation_vector(Vector3::default()),
+        Quaternion::identity()
+    );
+}
+
+#[test]
+fn derivative_of_rotation() {
+    let omega = Vector3::new(0., 0., 2.);
+    let q = Quaternion::from_axis_angle(Vector3::new(1., 0., 0.), Radians(0.5));
+    let h = 1e-3;
+    let ahead = Quaternion::from_rotation_vector(omega * h) * q;
+    let behind = Quaternion::from_rotation_vector(omega * -h) * q;
+    let numerical = (ahead - behind) * (0.5 / h);
+    let analytic = q.derivative(omega);
+
+    assert!((numerical - analytic).dot(numerical - analytic).sqrt() < 1e-3);
+}
+
+#[test]
+fn integrate_angular_velocity() {
+    let omega = Vector3::new(0., 0., std::f32::consts::PI);
+    let expected = Quaternion::from_axis_angle(
+        Vector3::new(0., 0., 1.),
+        Radians(std::f32::consts::FRAC_PI_2),
+    );
+    let mut first_order = Quaternion::identity();
+    for _ in 0..1000 {
+        first_order = first_order.integrate(omega, 0.5 / 1000.);
+    }
+    let exact = Quaternion::identity().integrate_exact(omega, 0.5);
+
+    assert!((exact.dot(expected) - 1.).abs() < 1e-6);
+    assert!((first_order.dot(expected) - 1.).abs() < 1e-5);
+}
+
+#[test]
+fn angular_velocity_between() {
+    let omega = Vector3::new(0.3, -0.2, 0.9);
+    let q0 = Quaternion::from_axis_angle(Vector3::new(1., 1., 0.), Radians(0.7));
+    let q1 = q0.integrate_exact(omega, 0.1);
+
+    assert!(vec_approx_eq(
+        Quaternion::angular_velocity_between(q0, q1, 0.1),
+        omega,
+        1e-4
+    ));
+}
+
+#[test]
+fn weighted_average_of_rotations() {
+    let axis = Vector3::new(0., 0., 1.);
+    let a = Quaternion::from_axis_angle(axis, Radians(0.2));
+    let b = Quaternion::from_axis_angle(axis, Radians(0.6));
+    let mean = Quaternion::weighted_average(&[(a, 1.), (b * -1., 1.)]);
+    let skewed = Quaternion::weighted_average(&[(a, 3.), (b, 1.)]);
+
+    assert!(
+        Quaternion::angle_between(mean, Quaternion::from_axis_angle(axis, Radians(0.4))) < 1e-3
+    );
+    assert!(Quaternion::angle_between(skewed, a) < Quaternion::angle_between(skewed, b));
+    assert_eq!(Quaternion::weighted_average(&[]), Quaternion::identity());
+}
+
+#[test]
+fn chordal_mean_of_rotations() {
+    let axis = Vector3::new(1., 1., 0.);
+    let rotations = [
+        Quaternion::from_axis_angle(axis, Radians(-0.3)),
+        Quaternion::from_axis_angle(axis, Radians(0.1)),
+        Quaternion::from_axis_angle(axis, Radians(0.5)),
+    ];
+    let mean = Quaternion::chordal_mean(&rotations);
+
+    assert!(
+        Quaternion::angle_between(mean, Quaternion::from_axis_angle(axis, Radians(0.1))) < 1e-3
+    );
+}
+
+#[test]
+fn angle_and_geodesic_distance() {
+    let a = Quaternion::from_axis_angle(Vector3::new(0., 1., 0.), Radians(0.25));
+    let b = Quaternion::from_axis_angle(Vector3::new(0., 1., 0.), Radians(1.));
+
+    assert!((Quaternion::angle_between(a, b) - 0.75).abs() < 1e-5);
+    assert!((a.geodesic_distance(b * -1.) - 0.75).abs() < 1e-5);
+    assert_eq!(Quaternion::angle_between(a, a), 0.);
+}
+
+#[test]
+fn variance_of_rotations() {
+    let axis = Vector3::new(0., 0., 1.);
+    let spread = [
+        (Quaternion::from_axis_angle(axis, Radians(-0.1)), 1.),
+        (Quaternion::from_axis_angle(axis, Radians(0.1)), 1.),
+    ];
+    let same = [(Quaternion::identity(), 1.), (Quaternion::identity(), 2.)];
+
+    assert!((Quaternion::variance(&spread) - 0.01).abs() < 1e-4);
+    assert_eq!(Quaternion::variance(&same), 0.);
+}
+
+#[test]
+fn axis_angle_accepts_degrees() {
+    let axis = Vector3::new(0., 1., 0.);
+    let from_degrees = Quaternion::from_axis_angle(axis, Degrees(90.));
+    let from_radians = Quaternion::from_axis_angle(axis, Radians(std::f32::consts::FRAC_PI_2));
+
+    assert!((from_degrees.dot(from_radians) - 1.).abs() < 1e-6);
+    assert!(vec_approx_eq(
+        from_degrees.to_matrix().col(0),
+        Matrix3x3::rotation_y(Degrees(90.)).col(0),
+        1e-6
+    ));
+}
+
+#[test]
+fn rotate_angle_accepts_radians_and_degrees() {
+    let q = Quaternion::new(0., Vector3::new(1., 2., 3.));
+    let axis = Vector3::new(0., 0., 1.);
+
+    assert!(vec_approx_eq(
+        q.rotate_angle(Radians(std::f32::consts::FRAC_PI_2), &axis),
+        q.rotate_angle(Degrees(90.), &axis),
+        1e-6
+    ));
+}
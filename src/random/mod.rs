@@ -0,0 +1,117 @@
+use std::f32::consts::TAU;
+
+use rand::distr::{Distribution, StandardUniform};
+use rand::Rng;
+
+use crate::prelude::{Matrix3x3, Quaternion, Vector3};
+
+/// Uniform points in the cube `[-1, 1)³`.
+#[derive(Debug, Clone, Copy)]
+pub struct UnitCube;
+
+/// Uniform directions on the unit sphere.
+#[derive(Debug, Clone, Copy)]
+pub struct UnitSphere;
+
+/// Uniform points inside the unit ball.
+#[derive(Debug, Clone, Copy)]
+pub struct UnitBall;
+
+/// Uniform directions on the unit hemisphere around `normal`.
+#[derive(Debug, Clone, Copy)]
+pub struct Hemisphere {
+    pub normal: Vector3,
+}
+
+/// Cosine-weighted directions on the unit hemisphere around `normal`.
+#[derive(Debug, Clone, Copy)]
+pub struct CosineHemisphere {
+    pub normal: Vector3,
+}
+
+/// Uniform rotations (Shoemake, 1992), as quaternions or rotation matrices.
+#[derive(Debug, Clone, Copy)]
+pub struct UniformRotation;
+
+fn symmetric_unit<R: Rng + ?Sized>(rng: &mut R) -> f32 {
+    rng.random::<f32>() * 2. - 1.
+}
+
+/// Maps `local`, expressed with +Z as the pole, into the frame around `normal`.
+fn orient(local: Vector3, normal: Vector3) -> Vector3 {
+    let n = normal.normalize();
+    let (t, b) = n.orthonormal_basis();
+    Matrix3x3::from_basis(t, b, n) * local
+}
+
+impl Distribution<Vector3> for StandardUniform {
+    /// Uniform points in `[0, 1)³`.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        Vector3::new(rng.random(), rng.random(), rng.random())
+    }
+}
+
+impl Distribution<Vector3> for UnitCube {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        Vector3::new(
+            symmetric_unit(rng),
+            symmetric_unit(rng),
+            symmetric_unit(rng),
+        )
+    }
+}
+
+impl Distribution<Vector3> for UnitSphere {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        let z = symmetric_unit(rng);
+        let phi = rng.random::<f32>() * TAU;
+        let r = (1. - z * z).max(0.).sqrt();
+        Vector3::new(r * phi.cos(), r * phi.sin(), z)
+    }
+}
+
+impl Distribution<Vector3> for UnitBall {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        let direction = UnitSphere.sample(rng);
+        direction * rng.random::<f32>().cbrt()
+    }
+}
+
+impl Distribution<Vector3> for Hemisphere {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        let mut local = UnitSphere.sample(rng);
+        local.z = local.z.abs();
+        orient(local, self.normal)
+    }
+}
+
+impl Distribution<Vector3> for CosineHemisphere {
+    /// Malley's method: uniform disk samples projected up onto the hemisphere.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        let r = rng.random::<f32>().sqrt();
+        let phi = rng.random::<f32>() * TAU;
+        let z = (1. - r * r).max(0.).sqrt();
+        orient(Vector3::new(r * phi.cos(), r * phi.sin(), z), self.normal)
+    }
+}
+
+impl Distribution<Quaternion> for UniformRotation {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quaternion {
+        let u1: f32 = rng.random();
+        let (s2, c2) = (rng.random::<f32>() * TAU).sin_cos();
+        let (s3, c3) = (rng.random::<f32>() * TAU).sin_cos();
+        let a = (1. - u1).sqrt();
+        let b = u1.sqrt();
+        Quaternion::new(b * c3, Vector3::new(a * s2, a * c2, b * s3))
+    }
+}
+
+impl Distribution<Matrix3x3> for UniformRotation {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Matrix3x3 {
+        let q: Quaternion = self.sample(rng);
+        q.to_matrix()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,89 @@
+use super::*;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const SAMPLES: usize = 2000;
+
+fn rng() -> StdRng {
+    StdRng::seed_from_u64(7)
+}
+
+fn mean(samples: &[Vector3]) -> Vector3 {
+    samples.iter().fold(Vector3::default(), |acc, v| acc + *v) / samples.len() as f32
+}
+
+#[test]
+fn seeded_sampling_is_deterministic() {
+    let a: Vec<Vector3> = rng().sample_iter(UnitSphere).take(10).collect();
+    let b: Vec<Vector3> = rng().sample_iter(UnitSphere).take(10).collect();
+    let q_a: Quaternion = rng().sample(UniformRotation);
+    let q_b: Quaternion = rng().sample(UniformRotation);
+
+    assert_eq!(a, b);
+    assert_eq!(q_a, q_b);
+}
+
+#[test]
+fn standard_and_cube() {
+    let mut rng = rng();
+    let unit: Vec<Vector3> = (0..SAMPLES).map(|_| rng.random()).collect();
+    let cube: Vec<Vector3> = (&mut rng).sample_iter(UnitCube).take(SAMPLES).collect();
+
+    for v in &unit {
+        assert!([v.x, v.y, v.z].iter().all(|c| (0. ..1.).contains(c)));
+    }
+    for v in &cube {
+        assert!([v.x, v.y, v.z].iter().all(|c| (-1. ..1.).contains(c)));
+    }
+    assert!(mean(&cube).magnitude() < 0.1);
+}
+
+#[test]
+fn sphere_and_ball() {
+    let mut rng = rng();
+    let sphere: Vec<Vector3> = (&mut rng).sample_iter(UnitSphere).take(SAMPLES).collect();
+    let ball: Vec<Vector3> = (&mut rng).sample_iter(UnitBall).take(SAMPLES).collect();
+
+    assert!(sphere.iter().all(|v| (v.magnitude() - 1.).abs() < 1e-5));
+    assert!(mean(&sphere).magnitude() < 0.1);
+    assert!(ball.iter().all(|v| v.magnitude() <= 1.));
+    let inside_half = ball.iter().filter(|v| v.magnitude() < 0.5).count() as f32;
+    assert!((inside_half / SAMPLES as f32 - 0.125).abs() < 0.03);
+}
+
+#[test]
+fn hemispheres() {
+    let normal = Vector3::new(0., 1., 1.).normalize();
+    let mut rng = rng();
+    let uniform: Vec<Vector3> = (&mut rng)
+        .sample_iter(Hemisphere { normal })
+        .take(SAMPLES)
+        .collect();
+    let cosine: Vec<Vector3> = (&mut rng)
+        .sample_iter(CosineHemisphere { normal })
+        .take(SAMPLES)
+        .collect();
+
+    assert!(uniform.iter().all(|v| v.dot(normal) >= 0.));
+    assert!(cosine.iter().all(|v| v.dot(normal) >= 0.));
+    assert!(cosine.iter().all(|v| (v.magnitude() - 1.).abs() < 1e-5));
+    assert!((mean(&uniform).dot(normal) - 0.5).abs() < 0.05);
+    assert!((mean(&cosine).dot(normal) - 2. / 3.).abs() < 0.05);
+}
+
+#[test]
+fn uniform_rotations() {
+    let mut rng = rng();
+    let rotations: Vec<Quaternion> = (&mut rng)
+        .sample_iter(UniformRotation)
+        .take(SAMPLES)
+        .collect();
+    let matrix: Matrix3x3 = rng.sample(UniformRotation);
+    let x = Vector3::new(1., 0., 0.);
+    let rotated: Vec<Vector3> = rotations.iter().map(|q| q.rotate(x)).collect();
+
+    assert!(rotations.iter().all(|q| (q.dot(*q) - 1.).abs() < 1e-5));
+    assert!(mean(&rotated).magnitude() < 0.1);
+    assert!(matrix.is_rotation(1e-5));
+}
@@ -1,6 +1,12 @@
 use std::fmt::Display;
 use std::ops;
 
+use crate::prelude::{MathError, Matrix3x3, Radians};
+
+mod vector2;
+
+pub use vector2::Vector2;
+
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub struct Vector3 {
     pub x: f32,
@@ -37,6 +43,175 @@ impl Vector3 {
             self
         }
     }
+
+    /// Component-wise minimum.
+    pub fn min(self, other: Vector3) -> Vector3 {
+        Vector3::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    /// Component-wise maximum.
+    pub fn max(self, other: Vector3) -> Vector3 {
+        Vector3::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+
+    pub fn abs(self) -> Vector3 {
+        Vector3::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    pub fn magnitude_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    pub fn is_normalized(self, eps: f32) -> bool {
+        (self.magnitude_squared() - 1.).abs() <= eps
+    }
+
+    pub fn distance(self, other: Vector3) -> f32 {
+        (self - other).magnitude()
+    }
+
+    pub fn distance_squared(self, other: Vector3) -> f32 {
+        (self - other).magnitude_squared()
+    }
+
+    /// Unsigned angle in `[0, π]`.
+    pub fn angle_between(self, other: Vector3) -> Radians {
+        Radians(self.cross(other).magnitude().atan2(self.dot(other)))
+    }
+
+    /// Angle in `(-π, π]` from `self` to `other`, positive when the rotation
+    /// is counter-clockwise looking down `about`.
+    pub fn signed_angle(self, other: Vector3, about: Vector3) -> Radians {
+        let cross = self.cross(other);
+        let angle = cross.magnitude().atan2(self.dot(other));
+        if cross.dot(about) < 0. {
+            Radians(-angle)
+        } else {
+            Radians(angle)
+        }
+    }
+
+    /// Component of `self` along `onto`. Projecting onto a zero vector gives
+    /// a zero vector.
+    pub fn project_onto(self, onto: Vector3) -> Vector3 {
+        let length_squared = onto.magnitude_squared();
+        if length_squared == 0. {
+            return Vector3::default();
+        }
+        onto * (self.dot(onto) / length_squared)
+    }
+
+    /// Component of `self` perpendicular to `from`.
+    pub fn reject_from(self, from: Vector3) -> Vector3 {
+        self - self.project_onto(from)
+    }
+
+    /// Mirrors `self` about the plane with unit `normal`.
+    pub fn reflect(self, normal: Vector3) -> Vector3 {
+        self - normal * (2. * self.dot(normal))
+    }
+
+    /// Refracts the unit direction `self` through a surface with unit `normal`
+    /// facing against it, where `eta` is the ratio of refractive indices.
+    /// Returns `None` on total internal reflection.
+    pub fn refract(self, normal: Vector3, eta: f32) -> Option<Vector3> {
+        let cos_i = self.dot(normal);
+        let k = 1. - eta * eta * (1. - cos_i * cos_i);
+        if k < 0. {
+            return None;
+        }
+        Some(self * eta - normal * (eta * cos_i + k.sqrt()))
+    }
+
+    pub fn lerp(self, other: Vector3, t: f32) -> Vector3 {
+        self + (other - self) * t
+    }
+
+    /// Constant angular speed interpolation between the directions of `self`
+    /// and `other`. The result is unit length.
+    pub fn slerp(self, other: Vector3, t: f32) -> Vector3 {
+        let from = self.normalize();
+        let to = other.normalize();
+        let angle = from.angle_between(to).0;
+        let sin = angle.sin();
+        if sin < 1e-5 {
+            if from.dot(to) > 0. {
+                return from.lerp(to, t).normalize();
+            }
+            let axis = from.any_orthogonal();
+            let (s, c) = (angle * t).sin_cos();
+            return from * c + axis * s;
+        }
+        (from * ((1. - t) * angle).sin() + to * (t * angle).sin()) / sin
+    }
+
+    /// Some unit vector perpendicular to `self`, or the zero vector when
+    /// `self` is zero.
+    pub fn any_orthogonal(self) -> Vector3 {
+        let other = if self.x.abs() < 0.9 * self.magnitude() {
+            Vector3::new(1., 0., 0.)
+        } else {
+            Vector3::new(0., 1., 0.)
+        };
+        self.cross(other).normalize()
+    }
+
+    /// Two unit vectors `(t, b)` such that `(t, b, self)` is a right-handed
+    /// orthonormal basis. `self` must be unit length. Branchless and continuous
+    /// except across `z = 0` (Duff et al., 2017).
+    pub fn orthonormal_basis(self) -> (Vector3, Vector3) {
+        let sign = 1f32.copysign(self.z);
+        let a = -1. / (sign + self.z);
+        let b = self.x * self.y * a;
+        (
+            Vector3::new(1. + sign * self.x * self.x * a, sign * b, -sign * self.x),
+            Vector3::new(b, sign + self.y * self.y * a, -self.y),
+        )
+    }
+
+    /// Orthonormalizes `a`, `b` and `c` in that order. Fails when the vectors
+    /// are linearly dependent.
+    pub fn gram_schmidt(
+        a: Vector3,
+        b: Vector3,
+        c: Vector3,
+    ) -> Result<(Vector3, Vector3, Vector3), MathError> {
+        let tolerance = 1e-6;
+        let scale = a.magnitude().max(b.magnitude()).max(c.magnitude());
+
+        if scale == 0. || a.magnitude() <= tolerance * scale {
+            return Err(MathError::Degenerate);
+        }
+        let e0 = a.normalize();
+
+        let e1 = b.reject_from(e0);
+        if e1.magnitude() <= tolerance * scale {
+            return Err(MathError::Degenerate);
+        }
+        let e1 = e1.normalize();
+
+        let e2 = c.reject_from(e0).reject_from(e1);
+        if e2.magnitude() <= tolerance * scale {
+            return Err(MathError::Degenerate);
+        }
+
+        Ok((e0, e1, e2.normalize()))
+    }
+
+    /// Skew-symmetric matrix such that `self.hat() * v == self.cross(v)`.
+    pub fn hat(self) -> Matrix3x3 {
+        Matrix3x3::new(
+            0., -self.z, self.y, self.z, 0., -self.x, -self.y, self.x, 0.,
+        )
+    }
 }
 
 impl Default for Vector3 {
@@ -138,6 +313,25 @@ impl ops::DivAssign<f32> for Vector3 {
     }
 }
 
+impl ops::Neg for Vector3 {
+    type Output = Vector3;
+    fn neg(self) -> Self::Output {
+        Vector3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl ops::Index<usize> for Vector3 {
+    type Output = f32;
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Vector3 index out of range: {index}"),
+        }
+    }
+}
+
 impl ops::Rem<Vector3> for Vector3 {
     type Output = Vector3;
     fn rem(self, rhs: Vector3) -> Self::Output {
@@ -267,3 +267,236 @@ fn test_normalize_zero_magnitude() {
     // For a zero vector, normalization should result in the same zero vector
     assert_eq!(normalized, zero_vector);
 }
+
+fn approx_eq(a: Vector3, b: Vector3) -> bool {
+    (a - b).magnitude() < 1e-5
+}
+
+#[test]
+fn magnitude_squared_and_distance() {
+    let a = Vector3::new(1., 2., 2.);
+    let b = Vector3::new(4., 6., 2.);
+
+    assert_eq!(a.magnitude_squared(), 9.);
+    assert_eq!(a.distance(b), 5.);
+    assert_eq!(a.distance_squared(b), 25.);
+}
+
+#[test]
+fn is_normalized() {
+    assert!(Vector3::new(0., 1., 0.).is_normalized(1e-6));
+    assert!(Vector3::new(1., 2., 3.).normalize().is_normalized(1e-6));
+    assert!(!Vector3::new(1., 1., 0.).is_normalized(1e-6));
+}
+
+#[test]
+fn angles() {
+    let x = Vector3::new(2., 0., 0.);
+    let y = Vector3::new(0., 3., 0.);
+    let z = Vector3::new(0., 0., 1.);
+    let quarter = std::f32::consts::FRAC_PI_2;
+
+    assert!((x.angle_between(y).0 - quarter).abs() < 1e-6);
+    assert_eq!(x.angle_between(x).0, 0.);
+    assert!((x.signed_angle(y, z).0 - quarter).abs() < 1e-6);
+    assert!((y.signed_angle(x, z).0 + quarter).abs() < 1e-6);
+}
+
+#[test]
+fn projection_and_rejection() {
+    let v = Vector3::new(3., 4., 5.);
+    let onto = Vector3::new(0., 2., 0.);
+
+    assert_eq!(v.project_onto(onto), Vector3::new(0., 4., 0.));
+    assert_eq!(v.reject_from(onto), Vector3::new(3., 0., 5.));
+    assert_eq!(v.project_onto(Vector3::default()), Vector3::default());
+}
+
+#[test]
+fn reflect_and_refract() {
+    let normal = Vector3::new(0., 1., 0.);
+    let incoming = Vector3::new(1., -1., 0.).normalize();
+
+    assert!(approx_eq(
+        incoming.reflect(normal),
+        Vector3::new(1., 1., 0.).normalize()
+    ));
+    assert!(approx_eq(incoming.refract(normal, 1.).unwrap(), incoming));
+
+    let refracted = incoming.refract(normal, 1. / 1.5).unwrap();
+    let sin_out = refracted.cross(normal * -1.).magnitude();
+    assert!((sin_out - (0.5f32.sqrt() / 1.5)).abs() < 1e-5);
+
+    let grazing = Vector3::new(1., -0.1, 0.).normalize();
+    assert_eq!(grazing.refract(normal, 1.5), None);
+}
+
+#[test]
+fn lerp_and_slerp() {
+    let a = Vector3::new(1., 0., 0.);
+    let b = Vector3::new(0., 1., 0.);
+    let half = std::f32::consts::FRAC_1_SQRT_2;
+
+    assert_eq!(a.lerp(b, 0.5), Vector3::new(0.5, 0.5, 0.));
+    assert!(approx_eq(
+        a.slerp(b * 3., 0.5),
+        Vector3::new(half, half, 0.)
+    ));
+    assert!(approx_eq(a.slerp(b, 0.), a));
+    assert!(approx_eq(a.slerp(b, 1.), b));
+    assert!(approx_eq(a.slerp(a, 0.3), a));
+
+    let opposite = a.slerp(a * -1., 0.5);
+    assert!(opposite.dot(a).abs() < 1e-5);
+    assert!(opposite.is_normalized(1e-5));
+}
+
+#[test]
+fn any_orthogonal() {
+    for v in [
+        Vector3::new(1., 0., 0.),
+        Vector3::new(0., 0., 5.),
+        Vector3::new(1., 2., 3.),
+        Vector3::new(-4., 0.1, 0.),
+    ] {
+        let o = v.any_orthogonal();
+
+        assert!(o.dot(v).abs() < 1e-5);
+        assert!(o.is_normalized(1e-5));
+    }
+
+    assert_eq!(Vector3::default().any_orthogonal(), Vector3::default());
+}
+
+#[test]
+fn orthonormal_basis() {
+    for n in [
+        Vector3::new(0., 0., 1.),
+        Vector3::new(0., 0., -1.),
+        Vector3::new(1., 2., 3.).normalize(),
+        Vector3::new(-0.3, 0.1, -2.).normalize(),
+    ] {
+        let (t, b) = n.orthonormal_basis();
+
+        assert!(t.is_normalized(1e-5) && b.is_normalized(1e-5));
+        assert!(t.dot(b).abs() < 1e-6 && t.dot(n).abs() < 1e-6 && b.dot(n).abs() < 1e-6);
+        assert!(approx_eq(t.cross(b), n));
+    }
+}
+
+#[test]
+fn gram_schmidt() {
+    let (e0, e1, e2) = Vector3::gram_schmidt(
+        Vector3::new(2., 0., 0.),
+        Vector3::new(1., 1., 0.),
+        Vector3::new(1., 1., 1.),
+    )
+    .unwrap();
+
+    assert_eq!(e0, Vector3::new(1., 0., 0.));
+    assert_eq!(e1, Vector3::new(0., 1., 0.));
+    assert_eq!(e2, Vector3::new(0., 0., 1.));
+}
+
+#[test]
+fn gram_schmidt_degenerate() {
+    let x = Vector3::new(1., 0., 0.);
+    let y = Vector3::new(0., 1., 0.);
+
+    assert_eq!(
+        Vector3::gram_schmidt(Vector3::default(), x, y),
+        Err(MathError::Degenerate)
+    );
+    assert_eq!(
+        Vector3::gram_schmidt(x, x * 3., y),
+        Err(MathError::Degenerate)
+    );
+    assert_eq!(
+        Vector3::gram_schmidt(x, y, x + y),
+        Err(MathError::Degenerate)
+    );
+}
+
+#[test]
+fn vector2_arithmetic() {
+    let mut a = Vector2::new(1., 2.);
+    let b = Vector2::new(3., -1.);
+
+    assert_eq!(a + b, Vector2::new(4., 1.));
+    assert_eq!(a - b, Vector2::new(-2., 3.));
+    assert_eq!(a * 2., Vector2::new(2., 4.));
+    assert_eq!(b / 2., Vector2::new(1.5, -0.5));
+    a += b;
+    a -= Vector2::new(1., 1.);
+    a *= 2.;
+    a /= 4.;
+    assert_eq!(a, Vector2::new(1.5, 0.));
+}
+
+#[test]
+fn vector2_products() {
+    let a = Vector2::new(3., 4.);
+    let b = Vector2::new(-4., 3.);
+
+    assert_eq!(a.dot(b), 0.);
+    assert_eq!(a.cross(b), 25.);
+    assert_eq!(a.perp(), b);
+    assert_eq!(a.magnitude(), 5.);
+    assert_eq!(a.normalize(), Vector2::new(0.6, 0.8));
+    assert_eq!(Vector2::default().normalize(), Vector2::default());
+}
+
+#[test]
+fn component_wise() {
+    let a = Vector3::new(1., -5., 3.);
+    let b = Vector3::new(-2., 4., 3.);
+
+    assert_eq!(a.min(b), Vector3::new(-2., -5., 3.));
+    assert_eq!(a.max(b), Vector3::new(1., 4., 3.));
+    assert_eq!(a.abs(), Vector3::new(1., 5., 3.));
+    assert_eq!(-a, Vector3::new(-1., 5., -3.));
+    assert_eq!([a[0], a[1], a[2]], [1., -5., 3.]);
+}
+
+#[test]
+fn convex_hull_2d() {
+    let v = Vector2::new;
+    let points = [
+        v(0., 0.),
+        v(1., 0.),
+        v(2., 0.),
+        v(2., 2.),
+        v(1., 1.),
+        v(0., 2.),
+        v(2., 2.),
+        v(0., 1.),
+        v(1., 2.),
+    ];
+
+    assert_eq!(
+        Vector2::convex_hull(&points),
+        vec![v(0., 0.), v(2., 0.), v(2., 2.), v(0., 2.)]
+    );
+    assert_eq!(
+        Vector2::convex_hull(&[v(0., 0.), v(1., 1.), v(2., 2.)]),
+        vec![v(0., 0.), v(2., 2.)]
+    );
+    assert_eq!(
+        Vector2::convex_hull(&[v(1., 1.), v(1., 1.)]),
+        vec![v(1., 1.)]
+    );
+}
+
+#[test]
+fn in_triangle_either_winding() {
+    let (a, b, c) = (
+        Vector2::new(0., 0.),
+        Vector2::new(2., 0.),
+        Vector2::new(0., 2.),
+    );
+    assert!(Vector2::new(0.5, 0.5).in_triangle(a, b, c));
+    assert!(Vector2::new(0.5, 0.5).in_triangle(a, c, b));
+    assert!(Vector2::new(1., 1.).in_triangle(a, b, c));
+    assert!(!Vector2::new(1.5, 1.5).in_triangle(a, b, c));
+    assert!(!Vector2::new(-0.5, 0.5).in_triangle(a, c, b));
+}
@@ -0,0 +1,150 @@
+use std::fmt::Display;
+use std::ops;
+
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Vector2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Vector2 {
+    pub fn new(x: f32, y: f32) -> Self {
+        Vector2 { x, y }
+    }
+
+    pub fn dot(self, vec: Vector2) -> f32 {
+        self.x * vec.x + self.y * vec.y
+    }
+
+    /// Z component of the 3D cross product, positive when `vec` is
+    /// counter-clockwise from `self`.
+    pub fn cross(self, vec: Vector2) -> f32 {
+        self.x * vec.y - self.y * vec.x
+    }
+
+    pub fn magnitude(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    pub fn magnitude_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    pub fn normalize(self) -> Vector2 {
+        let mag = self.magnitude();
+        if mag != 0. {
+            self / mag
+        } else {
+            self
+        }
+    }
+
+    /// `self` rotated a quarter turn counter-clockwise.
+    pub fn perp(self) -> Vector2 {
+        Vector2::new(-self.y, self.x)
+    }
+
+    /// Whether `self` lies inside the triangle `a`, `b`, `c` or on its
+    /// boundary, for either winding.
+    pub fn in_triangle(self, a: Vector2, b: Vector2, c: Vector2) -> bool {
+        let d1 = (b - a).cross(self - a);
+        let d2 = (c - b).cross(self - b);
+        let d3 = (a - c).cross(self - c);
+        let has_negative = d1 < 0. || d2 < 0. || d3 < 0.;
+        let has_positive = d1 > 0. || d2 > 0. || d3 > 0.;
+        !(has_negative && has_positive)
+    }
+
+    /// Andrew's monotone chain. Returns the hull counter-clockwise starting
+    /// from the lowest `x`, without duplicate or collinear points.
+    pub fn convex_hull(points: &[Vector2]) -> Vec<Vector2> {
+        let mut sorted = points.to_vec();
+        sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        sorted.dedup();
+        if sorted.len() < 3 {
+            return sorted;
+        }
+
+        let mut hull = monotone_chain(sorted.iter().copied());
+        hull.extend(monotone_chain(sorted.iter().rev().copied()));
+        hull
+    }
+}
+
+/// One half of the monotone chain, without its last point.
+fn monotone_chain(points: impl Iterator<Item = Vector2>) -> Vec<Vector2> {
+    let mut chain: Vec<Vector2> = Vec::new();
+    for p in points {
+        while let [.., a, b] = chain[..] {
+            if (b - a).cross(p - a) > 0. {
+                break;
+            }
+            chain.pop();
+        }
+        chain.push(p);
+    }
+    chain.pop();
+    chain
+}
+
+impl Display for Vector2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl ops::Add<Vector2> for Vector2 {
+    type Output = Vector2;
+    fn add(self, rhs: Vector2) -> Self::Output {
+        Vector2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl ops::AddAssign<Vector2> for Vector2 {
+    fn add_assign(&mut self, rhs: Vector2) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl ops::Sub<Vector2> for Vector2 {
+    type Output = Vector2;
+    fn sub(self, rhs: Vector2) -> Self::Output {
+        Vector2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl ops::SubAssign<Vector2> for Vector2 {
+    fn sub_assign(&mut self, rhs: Vector2) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl ops::Mul<f32> for Vector2 {
+    type Output = Vector2;
+    fn mul(self, rhs: f32) -> Self::Output {
+        Vector2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl ops::MulAssign<f32> for Vector2 {
+    fn mul_assign(&mut self, rhs: f32) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+impl ops::Div<f32> for Vector2 {
+    type Output = Vector2;
+    fn div(self, rhs: f32) -> Self::Output {
+        Vector2::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+impl ops::DivAssign<f32> for Vector2 {
+    fn div_assign(&mut self, rhs: f32) {
+        self.x /= rhs;
+        self.y /= rhs;
+    }
+}